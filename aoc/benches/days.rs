@@ -0,0 +1,71 @@
+// Times parsing and each part separately for every day that has an input.
+// Inputs are looked up the same way as `aoc run`, so AOC_INPUT_DIR applies
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use aoc::inputs::InputCache;
+use common::{Answer, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion, inputs: &InputCache, day: u8) {
+    let input = match inputs.read(day, None) {
+        Ok(input) => input,
+        Err(_) => return,
+    };
+
+    let parsed = match S::parse(&input.text) {
+        Ok(parsed) => parsed,
+        Err(err) => panic!("{}", err.render(&input.source_name, &input.text)),
+    };
+
+    let mut group = c.benchmark_group(format!("day{}", day));
+
+    // some parts take most of a second, so fewer samples keeps the suite quick
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input.text))));
+
+    // unsolved parts return straight away, so there's nothing to time
+    if S::part1(&parsed) != Answer::Unsolved {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    }
+
+    if S::part2(&parsed) != Answer::Unsolved {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    let inputs = InputCache::with_cache_dir(std::env::var_os("AOC_INPUT_DIR").map(PathBuf::from));
+
+    bench_day::<day1::Day1>(c, &inputs, 1);
+    bench_day::<day2::Day2>(c, &inputs, 2);
+    bench_day::<day3::Day3>(c, &inputs, 3);
+    bench_day::<day4::Day4>(c, &inputs, 4);
+    bench_day::<day5::Day5>(c, &inputs, 5);
+    bench_day::<day6::Day6>(c, &inputs, 6);
+    bench_day::<day7::Day7>(c, &inputs, 7);
+    bench_day::<day8::Day8>(c, &inputs, 8);
+    bench_day::<day9::Day9>(c, &inputs, 9);
+    bench_day::<day10::Day10>(c, &inputs, 10);
+    bench_day::<day11::Day11>(c, &inputs, 11);
+    bench_day::<day12::Day12>(c, &inputs, 12);
+    bench_day::<day13::Day13>(c, &inputs, 13);
+    bench_day::<day14::Day14>(c, &inputs, 14);
+    bench_day::<day15::Day15>(c, &inputs, 15);
+    bench_day::<day16::Day16>(c, &inputs, 16);
+    bench_day::<day17::Day17>(c, &inputs, 17);
+    bench_day::<day18::Day18>(c, &inputs, 18);
+    bench_day::<day19::Day19>(c, &inputs, 19);
+    bench_day::<day20::Day20>(c, &inputs, 20);
+    bench_day::<day21::Day21>(c, &inputs, 21);
+    bench_day::<day22::Day22>(c, &inputs, 22);
+    bench_day::<day23::Day23>(c, &inputs, 23);
+    bench_day::<day24::Day24>(c, &inputs, 24);
+    bench_day::<day25::Day25>(c, &inputs, 25);
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use common::Answer;
+use serde::Deserialize;
+
+// The accepted answers for each part, keyed like:
+//
+// [day1]
+// part1 = "69177"
+// part2 = "207456"
+#[derive(Debug, Default, Deserialize)]
+pub struct AnswerBook {
+    #[serde(flatten)]
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl AnswerBook {
+    // the answers file that's checked in at the root of the workspace
+    pub fn default_path() -> PathBuf {
+        crate::inputs::workspace_dir().join("answers.toml")
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|err| err.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read answers from {}: {}", path.display(), err))?;
+
+        AnswerBook::from_toml(&text)
+            .map_err(|err| format!("couldn't parse answers in {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.days.get(&format!("day{}", day))?;
+
+        let answer = match part {
+            1 => &answers.part1,
+            2 => &answers.part2,
+            _ => &None,
+        };
+
+        answer.as_deref()
+    }
+
+    // Compares an answer against the accepted one. Multi-line answers are
+    // compared ignoring surrounding whitespace, since they're easiest to
+    // record as multi-line strings
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        let expected = match self.get(day, part) {
+            Some(expected) => expected.trim(),
+            None => return Verdict::Unknown,
+        };
+
+        match answer {
+            Answer::Solved(actual) if actual.trim() == expected => Verdict::Pass,
+            Answer::Solved(_) | Answer::Failed(_) => Verdict::Fail {
+                expected: String::from(expected),
+            },
+            Answer::Unsolved => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown, // no accepted answer has been recorded
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    static TEST_ANSWERS: &str = r#"
+[day1]
+part1 = "24000"
+part2 = "45000"
+
+[day10]
+part2 = '''
+##..##..
+###...##
+'''
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers = AnswerBook::from_toml(TEST_ANSWERS).unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("24000"));
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = AnswerBook::from_toml(TEST_ANSWERS).unwrap();
+
+        assert_eq!(answers.check(1, 1, &Answer::from(24000u32)), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 2, &Answer::from(44999u32)),
+            Verdict::Fail {
+                expected: String::from("45000")
+            }
+        );
+        assert_eq!(answers.check(2, 1, &Answer::from(15u32)), Verdict::Unknown);
+        assert_eq!(answers.check(1, 1, &Answer::Unsolved), Verdict::Unknown);
+        assert_eq!(
+            answers.check(1, 1, &Answer::Failed(String::from("no path"))),
+            Verdict::Fail {
+                expected: String::from("24000")
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_multi_line() {
+        let answers = AnswerBook::from_toml(TEST_ANSWERS).unwrap();
+
+        let screen = Answer::from("##..##..\n###...##");
+
+        assert_eq!(answers.check(10, 2, &screen), Verdict::Pass);
+    }
+
+    #[test]
+    fn test_unknown_fields_rejected() {
+        assert!(AnswerBook::from_toml("[day1]\npart3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        let answers = AnswerBook::load(&AnswerBook::default_path()).unwrap();
+
+        assert!(answers.get(1, 1).is_some());
+    }
+}
@@ -0,0 +1,131 @@
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use common::{Answer, Result, Solution};
+
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+// The answers for a day, along with how long each phase took
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: Vec<SolvedPart>,
+}
+
+#[derive(Debug)]
+pub struct SolvedPart {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+impl Solved {
+    fn unsolved(parts: &[u8]) -> Self {
+        Solved {
+            parse_time: Duration::ZERO,
+            parts: parts
+                .iter()
+                .map(|part| SolvedPart {
+                    part: *part,
+                    answer: Answer::Unsolved,
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn answers(self) -> Vec<(u8, Answer)> {
+        self.parts
+            .into_iter()
+            .map(|solved| (solved.part, solved.answer))
+            .collect()
+    }
+}
+
+// Parses the input once, then solves each of the requested parts
+fn solve_parts<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+
+            let answer = match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => Answer::Unsolved,
+            };
+
+            SolvedPart {
+                part: *part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Solved { parse_time, parts })
+}
+
+pub fn solve(day: u8, input: &str, parts: &[u8]) -> Result<Solved> {
+    match day {
+        1 => solve_parts::<day1::Day1>(input, parts),
+        2 => solve_parts::<day2::Day2>(input, parts),
+        3 => solve_parts::<day3::Day3>(input, parts),
+        4 => solve_parts::<day4::Day4>(input, parts),
+        5 => solve_parts::<day5::Day5>(input, parts),
+        6 => solve_parts::<day6::Day6>(input, parts),
+        7 => solve_parts::<day7::Day7>(input, parts),
+        8 => solve_parts::<day8::Day8>(input, parts),
+        9 => solve_parts::<day9::Day9>(input, parts),
+        10 => solve_parts::<day10::Day10>(input, parts),
+        11 => solve_parts::<day11::Day11>(input, parts),
+        12 => solve_parts::<day12::Day12>(input, parts),
+        13 => solve_parts::<day13::Day13>(input, parts),
+        14 => solve_parts::<day14::Day14>(input, parts),
+        15 => solve_parts::<day15::Day15>(input, parts),
+        16 => solve_parts::<day16::Day16>(input, parts),
+        17 => solve_parts::<day17::Day17>(input, parts),
+        18 => solve_parts::<day18::Day18>(input, parts),
+        19 => solve_parts::<day19::Day19>(input, parts),
+        20 => solve_parts::<day20::Day20>(input, parts),
+        21 => solve_parts::<day21::Day21>(input, parts),
+        22 => solve_parts::<day22::Day22>(input, parts),
+        23 => solve_parts::<day23::Day23>(input, parts),
+        24 => solve_parts::<day24::Day24>(input, parts),
+        25 => solve_parts::<day25::Day25>(input, parts),
+        _ => Ok(Solved::unsolved(parts)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::*;
+
+    #[test]
+    fn test_solve_dispatches_to_day() {
+        let answers = solve(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", &[1, 2])
+            .unwrap()
+            .answers();
+
+        assert_eq!(
+            answers,
+            vec![(1, Answer::from(7usize)), (2, Answer::from(19usize))]
+        );
+    }
+
+    #[test]
+    fn test_solve_unsolved() {
+        assert_eq!(
+            solve(25, "", &[1]).unwrap().answers(),
+            vec![(1, Answer::Unsolved)]
+        );
+        assert_eq!(
+            solve(26, "", &[1]).unwrap().answers(),
+            vec![(1, Answer::Unsolved)]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// the year this workspace's puzzles are from. `aoc new-year` changes it
+pub const YEAR: u16 = 2022;
+
+// Passing this as the input path reads the input from stdin
+pub const STDIN_PATH: &str = "-";
+
+// the day crates live next to the runner in the workspace
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is a member of the workspace")
+}
+
+// A day's puzzle input, along with a name for where it came from
+pub struct Input {
+    pub source_name: String,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        year: u16,
+        day: u8,
+        searched: Vec<PathBuf>,
+    },
+    Unreadable {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound {
+                year,
+                day,
+                searched,
+            } => {
+                write!(f, "couldn't find an input for day {}. Looked in:", day)?;
+
+                for path in searched {
+                    write!(f, "\n    {}", path.display())?;
+                }
+
+                write!(
+                    f,
+                    "\nSet AOC_INPUT_DIR (or pass --input-dir) to a directory containing {}/day{:02}.txt, or pass --input",
+                    year, day
+                )
+            }
+            InputError::Unreadable { path, error } => {
+                write!(f, "couldn't read input from {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => write!(f, "couldn't read input from stdin: {}", error),
+        }
+    }
+}
+
+// Finds a year's puzzle inputs by day. Inputs are looked up in the cache
+// directory first, as `<cache_dir>/2022/day07.txt`, and then as `input.txt` in
+// the day's crate. Empty files are placeholders for inputs that haven't been
+// fetched, so they're skipped
+pub struct InputCache {
+    year: u16,
+    cache_dir: Option<PathBuf>,
+    workspace_dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(year: u16, cache_dir: Option<PathBuf>, workspace_dir: PathBuf) -> Self {
+        InputCache {
+            year,
+            cache_dir,
+            workspace_dir,
+        }
+    }
+
+    pub fn with_cache_dir(cache_dir: Option<PathBuf>) -> Self {
+        InputCache::new(YEAR, cache_dir, workspace_dir().to_path_buf())
+    }
+
+    // every path an input for the day could be at, in the order they're tried
+    pub fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+
+        if let Some(cache_dir) = &self.cache_dir {
+            candidates.push(
+                cache_dir
+                    .join(self.year.to_string())
+                    .join(format!("day{:02}.txt", day)),
+            );
+        }
+
+        candidates.push(
+            self.workspace_dir
+                .join(format!("day{}", day))
+                .join("input.txt"),
+        );
+
+        candidates
+    }
+
+    pub fn find(&self, day: u8) -> Result<PathBuf, InputError> {
+        let candidates = self.candidates(day);
+
+        let is_present = |path: &&PathBuf| {
+            std::fs::metadata(path)
+                .map(|metadata| metadata.is_file() && metadata.len() > 0)
+                .unwrap_or(false)
+        };
+
+        match candidates.iter().find(is_present) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                year: self.year,
+                day,
+                searched: candidates,
+            }),
+        }
+    }
+
+    pub fn has_input(&self, day: u8) -> bool {
+        self.find(day).is_ok()
+    }
+
+    // Reads the input for a day, from `path` if one is given (`-` for stdin),
+    // otherwise from the first candidate that exists
+    pub fn read(&self, day: u8, path: Option<&Path>) -> Result<Input, InputError> {
+        let path = match path {
+            Some(path) if path == Path::new(STDIN_PATH) => return read_stdin(),
+            Some(path) => path.to_path_buf(),
+            None => self.find(day)?,
+        };
+
+        let text = std::fs::read_to_string(&path).map_err(|error| InputError::Unreadable {
+            path: path.clone(),
+            error,
+        })?;
+
+        Ok(Input {
+            source_name: path.display().to_string(),
+            text,
+        })
+    }
+}
+
+fn read_stdin() -> Result<Input, InputError> {
+    let mut text = String::new();
+
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(InputError::Stdin)?;
+
+    Ok(Input {
+        source_name: String::from("<stdin>"),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inputs::*;
+
+    fn fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+    }
+
+    fn fixture_cache() -> InputCache {
+        InputCache::new(
+            2022,
+            Some(fixtures_dir().join("inputs")),
+            fixtures_dir().join("workspace"),
+        )
+    }
+
+    #[test]
+    fn test_finds_input_in_cache_dir() {
+        let path = fixture_cache().find(7).unwrap();
+
+        assert_eq!(path, fixtures_dir().join("inputs/2022/day07.txt"));
+    }
+
+    #[test]
+    fn test_falls_back_to_day_crate() {
+        let path = fixture_cache().find(1).unwrap();
+
+        assert_eq!(path, fixtures_dir().join("workspace/day1/input.txt"));
+    }
+
+    #[test]
+    fn test_skips_placeholder_inputs() {
+        let error = fixture_cache().find(13).unwrap_err();
+
+        assert!(matches!(error, InputError::NotFound { day: 13, .. }));
+    }
+
+    #[test]
+    fn test_not_found_names_where_it_looked() {
+        let error = fixture_cache().find(3).unwrap_err();
+
+        let message = error.to_string();
+
+        assert!(message.starts_with("couldn't find an input for day 3. Looked in:"));
+        let cached_path = fixtures_dir().join("inputs/2022/day03.txt");
+        let crate_path = fixtures_dir().join("workspace/day3/input.txt");
+
+        assert!(message.contains(&cached_path.display().to_string()));
+        assert!(message.contains(&crate_path.display().to_string()));
+    }
+
+    #[test]
+    fn test_read_explicit_path() {
+        let path = fixtures_dir().join("inputs/2022/day07.txt");
+
+        let input = fixture_cache().read(3, Some(&path)).unwrap();
+
+        assert_eq!(input.source_name, path.display().to_string());
+        assert!(input.text.starts_with("$ cd /"));
+    }
+
+    #[test]
+    fn test_read_missing_explicit_path() {
+        let path = fixtures_dir().join("missing.txt");
+
+        let error = fixture_cache().read(3, Some(&path)).err().unwrap();
+
+        assert!(matches!(error, InputError::Unreadable { .. }));
+    }
+}
@@ -0,0 +1,7 @@
+// The runner's pieces, shared by the `aoc` binary and the benchmarks
+pub mod answers;
+pub mod days;
+pub mod inputs;
+pub mod records;
+pub mod scaffold;
+pub mod timings;
@@ -0,0 +1,334 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use common::Answer;
+use log::LevelFilter;
+
+use aoc::answers::{AnswerBook, Verdict};
+use aoc::days::{self, Solved, SolvedPart};
+use aoc::inputs::{self, workspace_dir, Input, InputCache};
+use aoc::records::Record;
+use aoc::{scaffold, timings};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = format!("Runs the Advent of Code {} solutions", inputs::YEAR))]
+struct Cli {
+    /// Directory of cached puzzle inputs, laid out as <dir>/<year>/day07.txt.
+    /// Days without a cached input fall back to their crate's input.txt
+    #[arg(long, global = true, env = "AOC_INPUT_DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Print the solvers' diagnostics to stderr. Pass twice for more detail
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Print every diagnostic, e.g. each step of day9's rope
+    #[arg(long, global = true)]
+    trace: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `day 1 part 1: 69177`
+    Text,
+    /// A JSON object per line, e.g. `{"day":1,"part":1,"answer":"69177",...}`
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solver and print the answers
+    Run {
+        /// Day to run. Runs every day when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file instead of looking it up.
+        /// Use `-` to read from stdin
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+
+        /// Print how long parsing and each part took. JSON output always
+        /// includes the times
+        #[arg(short, long)]
+        time: bool,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check each day's answers against the accepted ones in answers.toml
+    Verify {
+        /// Day to verify. Verifies every day when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Read the accepted answers from this file instead
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Generate the crate for a day and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Overwrite the day's Cargo.toml and src/lib.rs even if src/lib.rs
+        /// has been changed from the generated one
+        #[arg(long)]
+        force: bool,
+    },
+    /// Replace every day with a fresh crate for a new year's puzzles, and
+    /// clear the accepted answers
+    NewYear {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+
+        /// Confirm deleting the current year's crates, inputs and answers
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+// Errors are returned fully formatted, ready to print
+fn run_day(day: u8, parts: &[u8], input: &Input, format: Format) -> Result<Solved, String> {
+    let solved = days::solve(day, &input.text, parts)
+        .map_err(|err| err.render(&input.source_name, &input.text))?;
+
+    match format {
+        Format::Text => {
+            for SolvedPart { part, answer, .. } in &solved.parts {
+                match answer {
+                    Answer::Solved(answer) => {
+                        // multi-line answers (e.g. day10's screen) read better starting on their own line
+                        let separator = if answer.contains('\n') { "\n" } else { " " };
+
+                        println!("day {} part {}:{}{}", day, part, separator, answer);
+                    }
+                    Answer::Failed(reason) => {
+                        eprintln!("day {} part {}: failed: {}", day, part, reason)
+                    }
+                    Answer::Unsolved => {}
+                }
+            }
+        }
+        Format::Json => {
+            for record in Record::from_solved(day, &solved) {
+                println!("{}", record.to_json());
+            }
+        }
+    }
+
+    Ok(solved)
+}
+
+fn read_input(inputs: &InputCache, day: u8, path: Option<&PathBuf>) -> Result<Input, String> {
+    inputs
+        .read(day, path.map(|path| path.as_path()))
+        .map_err(|err| format!("error: {}", err))
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+// Prints a line per part that has an answer or an accepted answer. Unsolved
+// days without accepted answers aren't mentioned
+fn verify_day(inputs: &InputCache, answers: &AnswerBook, day: u8, tally: &mut Tally) {
+    let recorded: Vec<u8> = [1, 2]
+        .into_iter()
+        .filter(|part| answers.get(day, *part).is_some())
+        .collect();
+
+    let input = match inputs.read(day, None) {
+        Ok(input) => input,
+        Err(_) => {
+            for part in recorded {
+                println!("day {} part {}: unknown (no input)", day, part);
+                tally.unknown += 1;
+            }
+            return;
+        }
+    };
+
+    let solved = match days::solve(day, &input.text, &[1, 2]) {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("{}", err.render(&input.source_name, &input.text));
+
+            // only the parts with accepted answers can fail
+            for part in [1, 2] {
+                if recorded.contains(&part) {
+                    println!("day {} part {}: FAIL (couldn't parse the input)", day, part);
+                    tally.failed += 1;
+                } else {
+                    println!(
+                        "day {} part {}: unknown (couldn't parse the input)",
+                        day, part
+                    );
+                    tally.unknown += 1;
+                }
+            }
+            return;
+        }
+    };
+
+    for (part, answer) in solved.answers() {
+        let verdict = answers.check(day, part, &answer);
+
+        let answer = match answer {
+            Answer::Solved(answer) => answer,
+            Answer::Unsolved if recorded.contains(&part) => String::from("unsolved"),
+            Answer::Unsolved => continue,
+            Answer::Failed(reason) => format!("failed: {}", reason),
+        };
+
+        match &verdict {
+            Verdict::Pass => tally.passed += 1,
+            Verdict::Fail { .. } => tally.failed += 1,
+            Verdict::Unknown => tally.unknown += 1,
+        }
+
+        match verdict {
+            Verdict::Fail { expected } if expected.contains('\n') || answer.contains('\n') => {
+                println!(
+                    "day {} part {}: FAIL\nexpected:\n{}\ngot:\n{}",
+                    day, part, expected, answer
+                )
+            }
+            Verdict::Fail { expected } => println!(
+                "day {} part {}: FAIL (expected {}, got {})",
+                day, part, expected, answer
+            ),
+            verdict => println!("day {} part {}: {}", day, part, verdict),
+        }
+    }
+}
+
+fn verify(
+    inputs: &InputCache,
+    day: Option<u8>,
+    answers_path: Option<PathBuf>,
+) -> Result<(), String> {
+    let answers_path = answers_path.unwrap_or_else(AnswerBook::default_path);
+    let answers = AnswerBook::load(&answers_path).map_err(|err| format!("error: {}", err))?;
+
+    let days = match day {
+        Some(day) => day..=day,
+        None => days::DAYS,
+    };
+
+    let mut tally = Tally::default();
+
+    for day in days {
+        verify_day(inputs, &answers, day, &mut tally);
+    }
+
+    println!(
+        "{} passed, {} failed, {} unknown",
+        tally.passed, tally.failed, tally.unknown
+    );
+
+    if tally.failed > 0 {
+        Err(format!(
+            "error: {} of the answers didn't match",
+            tally.failed
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// Only warnings are shown by default, so stdout is just the answers.
+// RUST_LOG can still be used to pick levels per crate
+fn init_logging(verbose: u8, trace: bool) {
+    let level = match (verbose, trace) {
+        (_, true) => LevelFilter::Trace,
+        (0, false) => LevelFilter::Warn,
+        (1, false) => LevelFilter::Info,
+        (_, false) => LevelFilter::Debug,
+    };
+
+    env_logger::Builder::new()
+        .filter_level(level)
+        .format_timestamp(None)
+        .parse_default_env()
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    init_logging(cli.verbose, cli.trace);
+
+    let inputs = InputCache::with_cache_dir(cli.input_dir);
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            time,
+            format,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            let days: Vec<u8> = match day {
+                Some(day) => vec![day],
+                // days without an input haven't been attempted yet
+                None => days::DAYS.filter(|day| inputs.has_input(*day)).collect(),
+            };
+
+            days.into_iter()
+                .map(|day| {
+                    read_input(&inputs, day, input.as_ref())
+                        .and_then(|input| run_day(day, &parts, &input, format))
+                        .map(|solved| (day, solved))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|solved| {
+                    if time && matches!(format, Format::Text) {
+                        print!("\n{}", timings::render_table(&parts, &solved));
+                    }
+
+                    let failed = solved
+                        .iter()
+                        .flat_map(|(_, solved)| &solved.parts)
+                        .filter(|part| matches!(part.answer, Answer::Failed(_)))
+                        .count();
+
+                    if failed > 0 {
+                        Err(format!("error: {} of the parts failed", failed))
+                    } else {
+                        Ok(())
+                    }
+                })
+        }
+        Command::Verify { day, answers } => verify(&inputs, day, answers),
+        Command::New { day, force } => scaffold::new_day(workspace_dir(), day, force),
+        Command::NewYear { year, force } => {
+            scaffold::new_year(workspace_dir(), year, inputs::YEAR, force)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
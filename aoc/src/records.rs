@@ -0,0 +1,116 @@
+use serde::Serialize;
+
+use common::Answer;
+
+use crate::days::Solved;
+use crate::timings::millis;
+
+// One solved or failed part, as emitted by `aoc run --format json`. A record
+// has either an answer or an error saying why the part failed
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+impl Record {
+    // A record for each solved or failed part of the day. Unsolved parts are
+    // left out.
+    // Parsing is shared between the parts, so each record has the same parse_ms
+    pub fn from_solved(day: u8, solved: &Solved) -> Vec<Record> {
+        solved
+            .parts
+            .iter()
+            .filter_map(|part| {
+                let (answer, error) = match &part.answer {
+                    Answer::Solved(answer) => (Some(answer.clone()), None),
+                    Answer::Failed(reason) => (None, Some(reason.clone())),
+                    Answer::Unsolved => return None,
+                };
+
+                Some(Record {
+                    day,
+                    part: part.part,
+                    answer,
+                    error,
+                    parse_ms: millis(solved.parse_time),
+                    solve_ms: millis(part.time),
+                })
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records only contain strings and numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::days::SolvedPart;
+    use crate::records::*;
+
+    #[test]
+    fn test_from_solved() {
+        let solved = Solved {
+            parse_time: Duration::from_micros(500),
+            parts: vec![
+                SolvedPart {
+                    part: 1,
+                    answer: Answer::from(24000u32),
+                    time: Duration::from_millis(2),
+                },
+                SolvedPart {
+                    part: 2,
+                    answer: Answer::Unsolved,
+                    time: Duration::ZERO,
+                },
+            ],
+        };
+
+        let records = Record::from_solved(1, &solved);
+
+        assert_eq!(
+            records,
+            vec![Record {
+                day: 1,
+                part: 1,
+                answer: Some(String::from("24000")),
+                error: None,
+                parse_ms: 0.5,
+                solve_ms: 2.0,
+            }]
+        );
+        assert_eq!(
+            records[0].to_json(),
+            r#"{"day":1,"part":1,"answer":"24000","parse_ms":0.5,"solve_ms":2.0}"#
+        );
+    }
+
+    #[test]
+    fn test_failed_part() {
+        let solved = Solved {
+            parse_time: Duration::from_micros(500),
+            parts: vec![SolvedPart {
+                part: 1,
+                answer: Answer::Failed(String::from("`E` can't be reached from `S`")),
+                time: Duration::from_millis(1),
+            }],
+        };
+
+        let records = Record::from_solved(12, &solved);
+
+        assert_eq!(
+            records[0].to_json(),
+            r#"{"day":12,"part":1,"error":"`E` can't be reached from `S`","parse_ms":0.5,"solve_ms":1.0}"#
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::days;
+
+// Each place a day has to be registered, as the line that registers it. The
+// lines are kept in order of day, so the new day goes after the closest
+// earlier one
+struct Registry {
+    path: &'static str, // relative to the workspace
+    line: fn(u8) -> String,
+}
+
+const REGISTRIES: [Registry; 4] = [
+    Registry {
+        path: "Cargo.toml",
+        line: |day| format!("    \"day{}\",", day),
+    },
+    Registry {
+        path: "aoc/Cargo.toml",
+        line: |day| format!("day{} = {{ path = \"../day{}\" }}", day, day),
+    },
+    Registry {
+        path: "aoc/src/days.rs",
+        line: |day| {
+            format!(
+                "        {} => solve_parts::<day{}::Day{}>(input, parts),",
+                day, day, day
+            )
+        },
+    },
+    Registry {
+        path: "aoc/benches/days.rs",
+        line: |day| {
+            format!(
+                "    bench_day::<day{}::Day{}>(c, &inputs, {});",
+                day, day, day
+            )
+        },
+    },
+];
+
+// where the year the workspace's puzzles are from is set
+const YEAR_PATH: &str = "aoc/src/inputs.rs";
+const YEAR_LINE_START: &str = "pub const YEAR: u16 = ";
+
+// answers.toml for a year with no accepted answers yet
+const EMPTY_ANSWERS: &str =
+    "# Accepted answers for the real puzzle inputs, checked by `aoc verify`\n";
+
+fn cargo_toml(day: u8) -> String {
+    format!(
+        "[package]
+name = \"day{day}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = {{ path = \"../common\" }}
+"
+    )
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(
+        "use common::{{Answer, Result, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    // Every line as it is, including the blank lines that separate sections
+    // in some puzzles
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Answer {{
+        Answer::Unsolved
+    }}
+
+    fn part2(_input: &Self::Input) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    // Once examples/1.txt and its answers in examples/1.part1.txt and
+    // examples/1.part2.txt are added, drop `unsolved` to check them
+    common::example_tests!(crate::Day{day}, unsolved);
+}}
+"
+    )
+}
+
+// The day a line mentions, e.g. 12 for `day12 = { path = "../day12" }`
+fn mentioned_day(line: &str) -> Option<u8> {
+    line.split("day").skip(1).find_map(|rest| {
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+        digits.parse().ok()
+    })
+}
+
+// Adds the day's line to `text`, keeping the registered days in order.
+// Returns None when the day is already registered
+fn register(text: &str, day: u8, line: fn(u8) -> String) -> Result<Option<String>, String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, existing)| {
+            let existing_day = mentioned_day(existing)?;
+
+            (*existing == line(existing_day)).then_some((index, existing_day))
+        })
+        .collect();
+
+    if registered
+        .iter()
+        .any(|(_, existing_day)| *existing_day == day)
+    {
+        return Ok(None);
+    }
+
+    let insert_at = match registered
+        .iter()
+        .rev()
+        .find(|(_, existing)| *existing < day)
+    {
+        Some((index, _)) => index + 1,
+        None => match registered.first() {
+            Some((index, _)) => *index,
+            None => return Err(String::from("couldn't find where the days are registered")),
+        },
+    };
+
+    lines.insert(insert_at, line(day));
+
+    let mut registered_text = lines.join("\n");
+    if text.ends_with('\n') {
+        registered_text.push('\n');
+    }
+
+    Ok(Some(registered_text))
+}
+
+// Generates the crate for a new day and registers it with the workspace and
+// the runner. A src/lib.rs that's been changed from the generated one is only
+// overwritten when `force` is set, and inputs and examples are never
+// overwritten
+pub fn new_day(workspace_dir: &Path, day: u8, force: bool) -> Result<(), String> {
+    let crate_dir = workspace_dir.join(format!("day{}", day));
+    let lib_path = crate_dir.join("src/lib.rs");
+
+    let is_stub = match fs::read_to_string(&lib_path) {
+        Ok(text) => text == lib_rs(day),
+        Err(_) => !lib_path.exists(),
+    };
+
+    if !is_stub && !force {
+        return Err(format!(
+            "error: {} has been changed from the generated one. Pass --force to overwrite it and {}",
+            lib_path.display(),
+            crate_dir.join("Cargo.toml").display()
+        ));
+    }
+
+    write_crate(&crate_dir, day)?;
+
+    register_day(workspace_dir, day)
+}
+
+// Starts the workspace on a new year: every day's crate is replaced with a
+// fresh one, without its input or examples, and the accepted answers are
+// cleared. That throws away the current year, so it needs `force`. Everything
+// that gets rewritten is checked before anything is deleted
+pub fn new_year(
+    workspace_dir: &Path,
+    year: u16,
+    current_year: u16,
+    force: bool,
+) -> Result<(), String> {
+    if year == current_year {
+        return Err(format!("error: the workspace is already for {}", year));
+    }
+
+    if !force {
+        return Err(format!(
+            "error: starting {} deletes every day's crate, input and examples from {} and clears answers.toml. Pass --force to go ahead",
+            year, current_year
+        ));
+    }
+
+    let year_path = workspace_dir.join(YEAR_PATH);
+
+    let text = fs::read_to_string(&year_path)
+        .map_err(|err| format!("error: couldn't read {}: {}", year_path.display(), err))?;
+    let text = set_year(&text, year).map_err(|err| format!("error: {} in {}", err, YEAR_PATH))?;
+
+    // the fresh crates aren't registered again, so every day has to be already
+    for registry in REGISTRIES.iter() {
+        let path = workspace_dir.join(registry.path);
+
+        let registered = fs::read_to_string(&path)
+            .map_err(|err| format!("error: couldn't read {}: {}", path.display(), err))?;
+
+        for day in days::DAYS {
+            if register(&registered, day, registry.line) != Ok(None) {
+                return Err(format!(
+                    "error: day{} isn't registered in {}. Run `aoc new {}` first",
+                    day, registry.path, day
+                ));
+            }
+        }
+    }
+
+    for day in days::DAYS {
+        let crate_dir = workspace_dir.join(format!("day{}", day));
+
+        if crate_dir.exists() {
+            fs::remove_dir_all(&crate_dir).map_err(|err| {
+                format!("error: couldn't remove {}: {}", crate_dir.display(), err)
+            })?;
+
+            println!("removed {}", crate_dir.display());
+        }
+
+        write_crate(&crate_dir, day)?;
+    }
+
+    write_file(&workspace_dir.join("answers.toml"), EMPTY_ANSWERS, true)?;
+    write_file(&year_path, &text, true)?;
+
+    println!("the workspace is now for {}", year);
+
+    Ok(())
+}
+
+// Sets the year in `text`, the source that defines `YEAR`
+fn set_year(text: &str, year: u16) -> Result<String, String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+
+    let year_line = lines
+        .iter_mut()
+        .find(|line| line.starts_with(YEAR_LINE_START))
+        .ok_or_else(|| String::from("couldn't find where the year is set"))?;
+
+    *year_line = format!("{}{};", YEAR_LINE_START, year);
+
+    let mut year_text = lines.join("\n");
+    if text.ends_with('\n') {
+        year_text.push('\n');
+    }
+
+    Ok(year_text)
+}
+
+// the day's Cargo.toml and src/lib.rs, and placeholders for its example and input
+fn write_crate(crate_dir: &Path, day: u8) -> Result<(), String> {
+    write_file(&crate_dir.join("Cargo.toml"), &cargo_toml(day), true)?;
+    write_file(&crate_dir.join("src/lib.rs"), &lib_rs(day), true)?;
+    // empty files are placeholders until the puzzle's example and input are pasted in
+    write_file(&crate_dir.join("examples/1.txt"), "", false)?;
+    write_file(&crate_dir.join("input.txt"), "", false)?;
+
+    Ok(())
+}
+
+// Adds the day to each registry that doesn't have it yet
+fn register_day(workspace_dir: &Path, day: u8) -> Result<(), String> {
+    for registry in REGISTRIES.iter() {
+        let path = workspace_dir.join(registry.path);
+
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("error: couldn't read {}: {}", path.display(), err))?;
+
+        if let Some(registered) = register(&text, day, registry.line)
+            .map_err(|err| format!("error: {} in {}", err, path.display()))?
+        {
+            fs::write(&path, registered)
+                .map_err(|err| format!("error: couldn't write {}: {}", path.display(), err))?;
+
+            println!("registered day{} in {}", day, registry.path);
+        }
+    }
+
+    Ok(())
+}
+
+fn write_file(path: &PathBuf, contents: &str, overwrite: bool) -> Result<(), String> {
+    if path.exists() && !overwrite {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("error: couldn't create {}: {}", parent.display(), err))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|err| format!("error: couldn't write {}: {}", path.display(), err))?;
+
+    println!("wrote {}", path.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    static TEST_MEMBERS: &str = "[workspace]
+members = [
+    \"aoc\",
+    \"common\",
+    \"day1\",
+    \"day2\",
+    \"day10\",
+]
+";
+
+    #[test]
+    fn test_mentioned_day() {
+        assert_eq!(mentioned_day("    \"day12\","), Some(12));
+        assert_eq!(
+            mentioned_day("    bench_day::<day3::Day3>(c, &inputs, 3);"),
+            Some(3)
+        );
+        assert_eq!(mentioned_day("fn solve(day: u8)"), None);
+        assert_eq!(mentioned_day("    \"common\","), None);
+    }
+
+    #[test]
+    fn test_register_in_order() {
+        let registered = register(TEST_MEMBERS, 3, REGISTRIES[0].line)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            registered,
+            "[workspace]
+members = [
+    \"aoc\",
+    \"common\",
+    \"day1\",
+    \"day2\",
+    \"day3\",
+    \"day10\",
+]
+"
+        );
+    }
+
+    #[test]
+    fn test_register_already_registered() {
+        assert_eq!(register(TEST_MEMBERS, 10, REGISTRIES[0].line), Ok(None));
+    }
+
+    #[test]
+    fn test_register_without_registered_days() {
+        assert!(register("[workspace]\n", 3, REGISTRIES[0].line).is_err());
+    }
+
+    #[test]
+    fn test_set_year() {
+        assert_eq!(
+            set_year("use std::io;\n\npub const YEAR: u16 = 2022;\n", 2023),
+            Ok(String::from(
+                "use std::io;\n\npub const YEAR: u16 = 2023;\n"
+            ))
+        );
+        assert!(set_year("const YEAR: u16 = 2022;", 2023).is_err());
+    }
+
+    #[test]
+    fn test_year_is_set_in_year_path() {
+        let text = fs::read_to_string(crate::inputs::workspace_dir().join(YEAR_PATH)).unwrap();
+
+        assert_eq!(set_year(&text, crate::inputs::YEAR), Ok(text));
+    }
+
+    #[test]
+    fn test_stub_days_are_generated() {
+        let text = fs::read_to_string(crate::inputs::workspace_dir().join("day25/src/lib.rs"));
+
+        assert_eq!(text.unwrap(), lib_rs(25));
+    }
+
+    #[test]
+    fn test_new_day_keeps_changed_crates() {
+        let error = new_day(crate::inputs::workspace_dir(), 1, false).unwrap_err();
+
+        assert!(error.contains("day1/src/lib.rs has been changed"));
+    }
+
+    #[test]
+    fn test_new_year_checks_before_deleting() {
+        let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/workspace");
+
+        let error = new_year(&workspace_dir, 2023, 2022, false).unwrap_err();
+        assert!(error.contains("Pass --force"));
+
+        // the fixture workspace has no runner to set the year in
+        assert!(new_year(&workspace_dir, 2023, 2022, true).is_err());
+        assert!(workspace_dir.join("day1/input.txt").exists());
+
+        assert!(new_year(&workspace_dir, 2022, 2022, true).is_err());
+    }
+
+    #[test]
+    fn test_every_day_is_registered() {
+        for registry in REGISTRIES.iter() {
+            let text =
+                fs::read_to_string(crate::inputs::workspace_dir().join(registry.path)).unwrap();
+
+            for day in crate::days::DAYS {
+                assert_eq!(register(&text, day, registry.line), Ok(None));
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use crate::days::Solved;
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+// e.g. `12.345ms`, which keeps the columns lined up for anything under 100s
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", millis(duration))
+}
+
+// A table of how long each day's phases took, with a row per day and a
+// column per phase
+pub fn render_table(parts: &[u8], solved: &[(u8, Solved)]) -> String {
+    let mut header = vec![String::from("day"), String::from("parse")];
+    header.extend(parts.iter().map(|part| format!("part {}", part)));
+    header.push(String::from("total"));
+
+    let mut rows = vec![header];
+
+    for (day, solved) in solved {
+        let mut row = vec![day.to_string(), format_duration(solved.parse_time)];
+        row.extend(solved.parts.iter().map(|part| format_duration(part.time)));
+
+        let total = solved.parse_time + solved.parts.iter().map(|part| part.time).sum::<Duration>();
+        row.push(format_duration(total));
+
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+
+    let mut table = String::new();
+
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect();
+
+        table.push_str(&cells.join("  "));
+        table.push('\n');
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::SolvedPart;
+    use crate::timings::*;
+    use common::Answer;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.500ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2000.000ms");
+    }
+
+    #[test]
+    fn test_render_table() {
+        let solved = Solved {
+            parse_time: Duration::from_micros(250),
+            parts: vec![
+                SolvedPart {
+                    part: 1,
+                    answer: Answer::from(1u32),
+                    time: Duration::from_micros(1000),
+                },
+                SolvedPart {
+                    part: 2,
+                    answer: Answer::from(2u32),
+                    time: Duration::from_millis(12),
+                },
+            ],
+        };
+
+        assert_eq!(
+            render_table(&[1, 2], &[(11, solved)]),
+            "day    parse   part 1    part 2     total
+ 11  0.250ms  1.000ms  12.000ms  13.250ms
+"
+        );
+    }
+}
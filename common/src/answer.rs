@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+
+// The answer to one part of a day's puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Solved(String),
+    Unsolved,
+    // the input has no answer, with the reason why
+    Failed(String),
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Answer::Solved(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Solved(answer) => write!(f, "{}", answer),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Failed(reason) => write!(f, "failed: {}", reason),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Solved(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Solved(String::from(answer))
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(answer: $number) -> Self {
+                    Answer::Solved(answer.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u16, u32, u64, u128, usize, i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(24000u32).to_string(), "24000");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+        assert_eq!(
+            Answer::Failed(String::from("no path")).to_string(),
+            "failed: no path"
+        );
+    }
+
+    #[test]
+    fn test_is_solved() {
+        assert!(Answer::from(-1i32).is_solved());
+        assert!(!Answer::Unsolved.is_solved());
+        assert!(!Answer::Failed(String::from("no path")).is_solved());
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+// Where in the input a parse error happened. Both are one-based, and the
+// column counts characters rather than bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub location: Option<Location>,
+    pub text: String,     // the offending text
+    pub expected: String, // a description of what should have been there
+    // For errors that aren't located yet, the byte offset of the text in the
+    // piece of a line it was found in, if known
+    offset: Option<usize>,
+}
+
+// The byte range of `text` in `piece`: where it was sliced from, otherwise
+// where it first appears. Empty text that wasn't sliced from the piece isn't
+// anywhere in particular
+fn span_in(piece: &str, text: &str) -> Option<Range<usize>> {
+    let piece_range = piece.as_bytes().as_ptr_range();
+    let text_range = text.as_bytes().as_ptr_range();
+
+    let start = if piece_range.start <= text_range.start && text_range.end <= piece_range.end {
+        text_range.start as usize - piece_range.start as usize
+    } else if text.is_empty() {
+        return None;
+    } else {
+        piece.find(text)?
+    };
+
+    Some(start..start + text.len())
+}
+
+impl ParseError {
+    // An error that isn't tied to a line yet, about text from an unknown
+    // place. Use `at` to locate it once the line is known
+    pub fn new(text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            location: None,
+            text: String::from(text),
+            expected: expected.into(),
+            offset: None,
+        }
+    }
+
+    // An error about `text` in `piece`, part of a line that isn't known yet,
+    // e.g. one field of it. Use `at` or `at_offset` to locate it
+    pub fn in_piece(piece: &str, text: &str, expected: impl Into<String>) -> Self {
+        let mut error = ParseError::new(text, expected);
+
+        error.offset = span_in(piece, text).map(|span| span.start);
+
+        error
+    }
+
+    // An error about the bytes in `span` of a line. `line_index` is
+    // zero-based
+    pub fn in_span(
+        line_index: usize,
+        line: &str,
+        span: Range<usize>,
+        expected: impl Into<String>,
+    ) -> Self {
+        let mut error = ParseError::new(&line[span.clone()], expected);
+
+        error.location = Some(Location {
+            line: line_index + 1,
+            column: line[..span.start].chars().count() + 1,
+        });
+
+        error
+    }
+
+    // An error about a single line of the input. `line_index` is zero-based, as
+    // produced by `input.lines().enumerate()`. The column is where `text` was
+    // sliced from `line`, or where it first appears in it. Empty text is at
+    // the end of the line
+    pub fn in_line(line_index: usize, line: &str, text: &str, expected: impl Into<String>) -> Self {
+        ParseError::in_piece(line, text, expected).at(line_index, line)
+    }
+
+    // An error at a known column of a line. Both indexes are zero-based, and
+    // the column counts characters
+    pub fn in_column(
+        line_index: usize,
+        column_index: usize,
+        text: &str,
+        expected: impl Into<String>,
+    ) -> Self {
+        let mut error = ParseError::new(text, expected);
+
+        error.location = Some(Location {
+            line: line_index + 1,
+            column: column_index + 1,
+        });
+
+        error
+    }
+
+    // Attaches the line the error happened on, for errors found in the whole
+    // line. Errors that already have a location keep it
+    pub fn at(self, line_index: usize, line: &str) -> Self {
+        self.at_offset(line_index, line, 0)
+    }
+
+    // Attaches the line the error happened on, for errors found in a piece of
+    // it starting `piece_start` bytes in. The column is where the text was
+    // in the piece if that's known. Otherwise it's where the text first
+    // appears in the line (the end of the line if there's no offending text),
+    // or the start of the line if it doesn't appear
+    pub fn at_offset(mut self, line_index: usize, line: &str, piece_start: usize) -> Self {
+        if self.location.is_some() {
+            return self;
+        }
+
+        let byte_index = match self.offset.take() {
+            Some(offset) => Some(piece_start + offset),
+            None if self.text.is_empty() => Some(line.len()),
+            None => line.find(&self.text),
+        };
+
+        let column = match byte_index.and_then(|byte_index| line.get(..byte_index)) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        };
+
+        self.location = Some(Location {
+            line: line_index + 1,
+            column,
+        });
+
+        self
+    }
+
+    // Renders the error with the offending line of `input` and a caret under
+    // the offending text, in the style of rustc's diagnostics. `source_name`
+    // says where the input came from, e.g. its path
+    pub fn render(&self, source_name: &str, input: &str) -> String {
+        let location = match &self.location {
+            Some(location) => location,
+            None => return format!("error: {}\n --> {}", self, source_name),
+        };
+
+        let source_line = input.lines().nth(location.line - 1).unwrap_or("");
+
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        let padding = " ".repeat(location.column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{} expected {}",
+            self.describe_found(),
+            gutter,
+            source_name,
+            location.line,
+            location.column,
+            gutter,
+            line_number,
+            source_line,
+            gutter,
+            padding,
+            carets,
+            self.expected
+        )
+    }
+
+    fn describe_found(&self) -> String {
+        if !self.text.is_empty() {
+            format!("unexpected `{}`", self.text)
+        } else if self.location.is_some() {
+            String::from("unexpected end of line")
+        } else {
+            String::from("invalid input")
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+
+        write!(f, "{}, expected {}", self.describe_found(), self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    static TEST_INPUT: &str = "R 4
+U 4
+X 3
+D 1";
+
+    #[test]
+    fn test_in_line() {
+        let error = ParseError::in_line(2, "X 3", "X", "a direction");
+
+        assert_eq!(error.location, Some(Location { line: 3, column: 1 }));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected `X`, expected a direction"
+        );
+    }
+
+    #[test]
+    fn test_at_keeps_existing_location() {
+        let error = ParseError::in_line(0, "1-2,3-x", "x", "a section number").at(5, "x");
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 7 }));
+    }
+
+    #[test]
+    fn test_repeated_text() {
+        let line = "A A";
+        let error = ParseError::in_line(0, line, &line[2..], "`X`, `Y` or `Z`");
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: unexpected `A`, expected `X`, `Y` or `Z`"
+        );
+
+        // text that wasn't sliced from the line falls back to searching for it
+        let error = ParseError::in_line(0, line, &String::from("A"), "`X`, `Y` or `Z`");
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 1 }));
+    }
+
+    #[test]
+    fn test_in_piece() {
+        // the second assignment, with multi-byte characters before it
+        let line = "é-3,3-";
+        let piece = &line[5..];
+
+        let error = ParseError::in_piece(piece, piece, "a section number").at_offset(0, line, 5);
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 5 }));
+        assert_eq!(error.text, "3-");
+
+        // the repeated `3` in the piece, rather than the first in the line
+        let error =
+            ParseError::in_piece(piece, &piece[..1], "a section number").at_offset(0, line, 5);
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 5 }));
+
+        // text from elsewhere is searched for in the line
+        let error = ParseError::new("-", "a section number").at(0, line);
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 2 }));
+    }
+
+    #[test]
+    fn test_in_span() {
+        let error = ParseError::in_span(0, "ö 3 3", 5..6, "a different number");
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: unexpected `3`, expected a different number"
+        );
+    }
+
+    #[test]
+    fn test_in_column() {
+        let error = ParseError::in_column(1, 3, "S", "only one start square");
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: unexpected `S`, expected only one start square"
+        );
+    }
+
+    #[test]
+    fn test_at_end_of_line() {
+        let error = ParseError::in_line(0, "addx", "", "a number to add");
+
+        assert_eq!(error.location, Some(Location { line: 1, column: 5 }));
+    }
+
+    #[test]
+    fn test_render() {
+        let error = ParseError::in_line(2, "X 3", "X", "one of `L`, `R`, `U` or `D`");
+
+        assert_eq!(
+            error.render("day9/input.txt", TEST_INPUT),
+            "error: unexpected `X`
+ --> day9/input.txt:3:1
+  |
+3 | X 3
+  | ^ expected one of `L`, `R`, `U` or `D`"
+        );
+    }
+
+    #[test]
+    fn test_render_without_location() {
+        let error = ParseError::new("", "at least one line");
+
+        assert_eq!(
+            error.render("day9/input.txt", TEST_INPUT),
+            "error: invalid input, expected at least one line
+ --> day9/input.txt"
+        );
+    }
+}
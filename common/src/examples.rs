@@ -0,0 +1,264 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, Solution};
+
+// Generates `example_part1` and `example_part2` tests, which check every
+// example in the crate's `examples` directory. Invoke it inside a day's
+// `tests` module:
+//
+//     common::example_tests!(Day1);
+//
+// Days that haven't been solved yet pass `unsolved`, which marks the tests as
+// ignored rather than checking examples that don't exist:
+//
+//     common::example_tests!(Day13, unsolved);
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, unsolved) => {
+        #[test]
+        #[ignore = "the day isn't solved yet"]
+        fn example_part1() {}
+
+        #[test]
+        #[ignore = "the day isn't solved yet"]
+        fn example_part2() {}
+    };
+    ($solution:ty) => {
+        #[test]
+        fn example_part1() {
+            $crate::check_examples::<$solution>(
+                std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples")),
+                1,
+            );
+        }
+
+        #[test]
+        fn example_part2() {
+            $crate::check_examples::<$solution>(
+                std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples")),
+                2,
+            );
+        }
+    };
+}
+
+// Each example input in the directory that has an expected answer for the
+// part, in order of name. An example's input is `<name>.txt` and its expected
+// answers are `<name>.part1.txt` and `<name>.part2.txt`, so examples that only
+// apply to one part leave out the other's answer
+fn examples(examples_dir: &Path, part: u8) -> Vec<(PathBuf, PathBuf)> {
+    let entries = match fs::read_dir(examples_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut examples: Vec<(PathBuf, PathBuf)> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+
+            if name.contains('.') {
+                return None; // an expected answer
+            }
+
+            let expected_path = examples_dir.join(format!("{}.part{}.txt", name, part));
+
+            expected_path.exists().then_some((path, expected_path))
+        })
+        .collect();
+
+    examples.sort();
+
+    examples
+}
+
+// Answer files that don't belong to an example input, e.g. `1.part_1.txt` or
+// `2.part1.txt` without a `2.txt`. They'd otherwise be silently skipped
+fn stray_answers(examples_dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(examples_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut stray: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) => name,
+                None => return false,
+            };
+
+            let example = match name.split_once('.') {
+                _ if !name.ends_with(".txt") => return false, // not an example
+                Some((_, "txt")) | None => return false,      // an input
+                Some((example, _)) => example,
+            };
+
+            let is_answer = ["part1.txt", "part2.txt"]
+                .iter()
+                .any(|suffix| name == format!("{}.{}", example, suffix));
+
+            !is_answer || !examples_dir.join(format!("{}.txt", example)).exists()
+        })
+        .collect();
+
+    stray.sort();
+
+    stray
+}
+
+// Solves the part for each example and panics listing every answer that
+// doesn't match. Answers are compared ignoring surrounding whitespace, so the
+// files can end in a newline. Also panics if there are no examples for the
+// part, or answer files that don't match an example, so a missing or
+// misnamed file can't make the check pass without checking anything
+pub fn check_examples<S: Solution>(examples_dir: &Path, part: u8) {
+    let stray = stray_answers(examples_dir);
+    if !stray.is_empty() {
+        let names: Vec<String> = stray
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+
+        panic!(
+            "answer files should be named `<name>.part1.txt` or `<name>.part2.txt` after an \
+             example `<name>.txt`, but these aren't:\n{}",
+            names.join("\n")
+        );
+    }
+
+    let examples = examples(examples_dir, part);
+    if examples.is_empty() {
+        panic!(
+            "no examples for part {} in {}: add an input `<name>.txt` and its answer \
+             `<name>.part{}.txt`, or pass `unsolved` to example_tests!",
+            part,
+            examples_dir.display(),
+            part
+        );
+    }
+
+    let mut failures = Vec::new();
+
+    for (input_path, expected_path) in examples {
+        let input = fs::read_to_string(&input_path).unwrap();
+        let expected = fs::read_to_string(&expected_path).unwrap();
+
+        let source_name = input_path.display().to_string();
+
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => panic!("{}", err.render(&source_name, &input)),
+        };
+
+        let answer = match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        };
+
+        match answer {
+            Answer::Solved(actual) if actual.trim() == expected.trim() => {}
+            answer => failures.push(format!(
+                "{} part {}: expected\n{}\ngot\n{}",
+                source_name,
+                part,
+                expected.trim(),
+                answer
+            )),
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("{}", failures.join("\n\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::*;
+    use crate::Result;
+
+    // sums the numbers in part 1, and multiplies them in part 2
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            Answer::from(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            Answer::from(input.iter().product::<u32>())
+        }
+    }
+
+    fn fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/examples")
+    }
+
+    #[test]
+    fn test_examples_for_part() {
+        let names = |part| {
+            examples(&fixtures_dir(), part)
+                .into_iter()
+                .map(|(input, _)| input.file_name().unwrap().to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(1), vec!["1.txt", "2.txt"]);
+        assert_eq!(names(2), vec!["1.txt"]);
+    }
+
+    #[test]
+    fn test_missing_examples_dir() {
+        assert!(examples(&fixtures_dir().join("missing"), 1).is_empty());
+    }
+
+    #[test]
+    fn test_check_examples() {
+        check_examples::<Numbers>(&fixtures_dir(), 1);
+        check_examples::<Numbers>(&fixtures_dir(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "no examples for part 1")]
+    fn test_check_missing_examples_dir() {
+        check_examples::<Numbers>(&fixtures_dir().join("missing"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "no examples for part 1")]
+    fn test_check_examples_without_answers() {
+        // the only example there has just a part 2 answer
+        check_examples::<Numbers>(&fixtures_dir().join("../wrong_examples"), 1);
+    }
+
+    #[test]
+    fn test_stray_answers() {
+        let names: Vec<String> = stray_answers(&fixtures_dir().join("../stray_examples"))
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap().to_owned())
+            .collect();
+
+        assert_eq!(names, vec!["1.part_2.txt", "2.part1.txt"]);
+        assert!(stray_answers(&fixtures_dir()).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "1.part_2.txt")]
+    fn test_check_stray_answers() {
+        check_examples::<Numbers>(&fixtures_dir().join("../stray_examples"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "part 2: expected\n7\ngot\n6")]
+    fn test_check_examples_mismatch() {
+        check_examples::<Numbers>(&fixtures_dir().join("../wrong_examples"), 2);
+    }
+}
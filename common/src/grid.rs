@@ -0,0 +1,460 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, Result};
+
+// (column, row), with (0, 0) in the top left corner
+pub type Position = (usize, usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    // clockwise starting from up
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    // how far a step in this direction moves, as (columns, rows)
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::Right => Direction::Left,
+            Direction::DownRight => Direction::UpLeft,
+            Direction::Down => Direction::Up,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::Left => Direction::Right,
+            Direction::UpLeft => Direction::DownRight,
+        }
+    }
+}
+
+// A rectangular grid of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    // Builds a grid from its cells, row by row. Panics if the cells don't
+    // make up whole rows
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't make up rows of {}",
+            cells.len(),
+            width
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    // Parses a grid with a line per row and a character per cell.
+    // `parse_cell` returns a description of what was expected for characters
+    // it doesn't accept. Every row has to be as wide as the first, and
+    // `cell_name` (e.g. "trees") describes the row's cells when one isn't.
+    // Trailing blank lines are ignored, and there has to be at least one row
+    pub fn parse<F>(input: &str, cell_name: &str, mut parse_cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> std::result::Result<T, String>,
+    {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        if lines.is_empty() {
+            return Err(ParseError::new(
+                "",
+                format!("at least one row of {}", cell_name),
+            ));
+        }
+
+        let width = lines[0].trim_end().chars().count();
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (line_index, line) in lines.iter().enumerate() {
+            let row = line.trim_end();
+
+            if row.chars().count() != width {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    row,
+                    format!("a row of {} {}, like the first row", width, cell_name),
+                ));
+            }
+
+            for (column_index, character) in row.chars().enumerate() {
+                let cell = parse_cell(character).map_err(|expected| {
+                    ParseError::in_column(
+                        line_index,
+                        column_index,
+                        &character.to_string(),
+                        expected,
+                    )
+                })?;
+
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (column, row): Position) -> bool {
+        column < self.width && row < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    fn index_of(&self, (column, row): Position) -> usize {
+        row * self.width + column
+    }
+
+    // every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (column, row)))
+    }
+
+    // every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // the position of the first cell, row by row, that matches
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {} is outside the grid", column);
+
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    // the position one step away in the direction, if it's in the grid
+    pub fn step(&self, (column, row): Position, direction: Direction) -> Option<Position> {
+        let (column_offset, row_offset) = direction.offset();
+
+        let next = (
+            column.checked_add_signed(column_offset)?,
+            row.checked_add_signed(row_offset)?,
+        );
+
+        self.contains(next).then_some(next)
+    }
+
+    // The positions from `start` to the edge of the grid in the direction,
+    // not including `start`
+    pub fn ray(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let mut current = start;
+
+        std::iter::from_fn(move || {
+            current = self.step(current, direction)?;
+            Some(current)
+        })
+    }
+
+    // the positions above, right of, below and left of `position`, where those are in the grid
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    // the neighbors4 along with the diagonal neighbors
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    // Draws the grid with a character per cell and a line per row
+    pub fn render(&self, mut render_cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut render_cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", position, width, height),
+        }
+    }
+}
+
+// Each row on its own line, with the cells written one after the other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    static TEST_INPUT: &str = "30373
+25512
+65332
+";
+
+    fn parse_digits(input: &str) -> Result<Grid<u32>> {
+        Grid::parse(input, "digits", |character| {
+            character
+                .to_digit(10)
+                .ok_or_else(|| String::from("a digit"))
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(3, 1)], 1);
+        assert_eq!(grid.row(2), &[6, 5, 3, 3, 2]);
+        assert_eq!(grid.column(4).copied().collect::<Vec<u32>>(), vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_digits("303\n25\n653").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `25`, expected a row of 3 digits, like the first row"
+        );
+
+        let error = parse_digits("\n\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid input, expected at least one row of digits"
+        );
+
+        let error = parse_digits("303\n2x5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected `x`, expected a digit"
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(grid.get((4, 2)), Some(&2));
+        assert_eq!(grid.get((5, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "(5, 0) is outside the 5x3 grid")]
+    fn test_index_out_of_bounds() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        let _ = grid[(5, 0)];
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<Position>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors4((2, 1)).collect::<Vec<Position>>(),
+            vec![(2, 0), (3, 1), (2, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbors8((2, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((4, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            grid.ray((1, 1), Direction::Right)
+                .collect::<Vec<Position>>(),
+            vec![(2, 1), (3, 1), (4, 1)]
+        );
+        assert_eq!(
+            grid.ray((1, 1), Direction::UpLeft)
+                .collect::<Vec<Position>>(),
+            vec![(0, 0)]
+        );
+        assert_eq!(grid.ray((1, 0), Direction::Up).count(), 0);
+    }
+
+    #[test]
+    fn test_opposite() {
+        for direction in Direction::ALL {
+            let (column_offset, row_offset) = direction.offset();
+
+            assert_eq!(direction.opposite().offset(), (-column_offset, -row_offset));
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = parse_digits(TEST_INPUT).unwrap();
+
+        assert_eq!(grid.to_string(), TEST_INPUT.trim_end());
+        assert_eq!(
+            grid.render(|digit| if *digit > 4 { '#' } else { '.' }),
+            "...#.\n.##..\n##..."
+        );
+    }
+
+    #[test]
+    fn test_from_cells() {
+        let grid = Grid::from_cells(2, vec!['#', '.', '.', '#']);
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.find(|cell| *cell == '#'), Some((0, 0)));
+        assert!(grid.map(|cell| *cell == '#')[(1, 1)]);
+    }
+}
@@ -0,0 +1,12 @@
+mod answer;
+mod error;
+mod examples;
+mod grid;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Location, ParseError, Result};
+pub use examples::check_examples;
+pub use grid::{Direction, Grid, Position};
+pub use solution::Solution;
@@ -0,0 +1,334 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// A path found by a search, from the start to the goal including both ends
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    // the number of moves along the path
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+}
+
+// Every node the search has reached, along with the node it was reached from.
+// Nodes are stored once and referred to by their index, so they only need to
+// be hashable
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    ids: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Visited {
+            nodes: vec![start.clone()],
+            parents: vec![None],
+            ids: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn add(&mut self, node: N, parent: usize) -> usize {
+        let id = self.nodes.len();
+
+        self.nodes.push(node.clone());
+        self.parents.push(Some(parent));
+        self.ids.insert(node, id);
+
+        id
+    }
+
+    fn path_to(&self, id: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[id].clone()];
+
+        let mut current = id;
+        while let Some(parent) = self.parents[current] {
+            path.push(self.nodes[parent].clone());
+            current = parent;
+        }
+
+        path.reverse();
+
+        path
+    }
+}
+
+// Breadth-first search for the fewest moves from `start` to a node matching
+// `is_goal`. The cost of the path is its number of steps
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut frontier = VecDeque::from([(0, 0)]);
+
+    while let Some((id, steps)) = frontier.pop_front() {
+        if is_goal(&visited.nodes[id]) {
+            return Some(Path {
+                nodes: visited.path_to(id),
+                cost: steps,
+            });
+        }
+
+        let current = visited.nodes[id].clone();
+
+        for neighbor in neighbors(&current) {
+            if !visited.ids.contains_key(&neighbor) {
+                let neighbor_id = visited.add(neighbor, id);
+                frontier.push_back((neighbor_id, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// The fewest moves from `start` to every node that can be reached from it,
+// found with a single breadth-first search
+pub fn distances<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut frontier = VecDeque::from([(start, 0)]);
+
+    while let Some((current, steps)) = frontier.pop_front() {
+        for neighbor in neighbors(&current) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), steps + 1);
+                frontier.push_back((neighbor, steps + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+// Dijkstra's algorithm, for the cheapest path from `start` to a node matching
+// `is_goal` when moves have different costs. `cost` is the cost of moving
+// between two neighboring nodes
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    cost: impl FnMut(&N, &N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = N>,
+{
+    astar(start, neighbors, cost, |_| C::default(), is_goal)
+}
+
+// A*, which is Dijkstra's algorithm guided by `heuristic`: an estimate of the
+// cost from a node to the goal. The path is only guaranteed to be the
+// cheapest if the heuristic never overestimates
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut cost: impl FnMut(&N, &N) -> C,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = N>,
+{
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    let mut visited = Visited::new(start);
+    let mut best_costs = vec![C::default()];
+
+    while let Some(Reverse((_, cost_so_far, id))) = frontier.pop() {
+        // a cheaper way to this node was found after this one was queued
+        if cost_so_far > best_costs[id] {
+            continue;
+        }
+
+        if is_goal(&visited.nodes[id]) {
+            return Some(Path {
+                nodes: visited.path_to(id),
+                cost: cost_so_far,
+            });
+        }
+
+        let current = visited.nodes[id].clone();
+
+        for neighbor in neighbors(&current) {
+            let cost_to_neighbor = cost_so_far + cost(&current, &neighbor);
+            let estimate = cost_to_neighbor + heuristic(&neighbor);
+
+            let neighbor_id = match visited.ids.get(&neighbor) {
+                Some(&neighbor_id) if cost_to_neighbor >= best_costs[neighbor_id] => continue,
+                Some(&neighbor_id) => {
+                    visited.parents[neighbor_id] = Some(id);
+                    best_costs[neighbor_id] = cost_to_neighbor;
+                    neighbor_id
+                }
+                None => {
+                    best_costs.push(cost_to_neighbor);
+                    visited.add(neighbor, id)
+                }
+            };
+
+            frontier.push(Reverse((estimate, cost_to_neighbor, neighbor_id)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+    use crate::Grid;
+
+    // `#` are walls
+    static TEST_MAZE: &str = "S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(TEST_MAZE, "squares", Ok).unwrap()
+    }
+
+    fn open_neighbors(maze: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        maze.neighbors4(position)
+            .filter(|neighbor| maze[*neighbor] != '#')
+            .collect()
+    }
+
+    // the number of rows and columns between two positions
+    fn manhattan_distance(from: (usize, usize), to: (usize, usize)) -> usize {
+        from.0.abs_diff(to.0) + from.1.abs_diff(to.1)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+
+        let path = bfs(
+            (0, 0),
+            |position| open_neighbors(&maze, *position),
+            |position| *position == (7, 4),
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.steps(), 15);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(7, 4)));
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        let path = bfs(3, |node| vec![node + 1], |node| *node == 3).unwrap();
+
+        assert_eq!(path.nodes, vec![3]);
+        assert_eq!(path.steps(), 0);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let maze = maze();
+
+        // a wall, which can't be moved into
+        let wall = |position: &(usize, usize)| *position == (3, 0);
+
+        assert_eq!(
+            bfs((0, 0), |position| open_neighbors(&maze, *position), wall),
+            None
+        );
+        assert_eq!(
+            dijkstra(
+                (0, 0),
+                |position| open_neighbors(&maze, *position),
+                |_, _| 1,
+                wall
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let maze = maze();
+
+        let distances = distances((0, 0), |position| open_neighbors(&maze, *position));
+
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(0, 4)], 4);
+        assert_eq!(distances[&(7, 4)], 15);
+        assert!(!distances.contains_key(&(3, 0)));
+    }
+
+    #[test]
+    fn test_dijkstra_prefers_cheaper_moves() {
+        // going straight from 0 to 3 is expensive, so it's cheaper to go the long way round
+        let edges = HashMap::from([
+            (0, vec![(1, 1), (3, 10)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+        ]);
+
+        let path = dijkstra(
+            0,
+            |node| edges.get(node).into_iter().flatten().map(|(to, _)| *to),
+            |from, to| {
+                edges[from]
+                    .iter()
+                    .find(|(neighbor, _)| neighbor == to)
+                    .map(|(_, cost)| *cost)
+                    .unwrap()
+            },
+            |node| *node == 3,
+        )
+        .unwrap();
+
+        assert_eq!(path.nodes, vec![0, 1, 2, 3]);
+        assert_eq!(path.cost, 3);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let maze = maze();
+        let goal = (7, 4);
+
+        let path = astar(
+            (0, 0),
+            |position| open_neighbors(&maze, *position),
+            |_, _| 1,
+            |position| manhattan_distance(*position, goal),
+            |position| *position == goal,
+        )
+        .unwrap();
+
+        let expected = dijkstra(
+            (0, 0),
+            |position| open_neighbors(&maze, *position),
+            |_, _| 1,
+            |position| *position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.cost, 15);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{Answer, Result};
+
+// Implemented by every day. The puzzle input is parsed once and then handed
+// to each part, so parts that need to mutate state work on their own copy.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
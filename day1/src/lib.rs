@@ -0,0 +1,413 @@
+mod stats;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+use common::{Answer, ParseError, Result, Solution};
+
+pub use stats::{CalorieStats, Distribution, Outlier, Percentile};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FoodItem {
+    calories: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<FoodItem>,
+}
+
+impl Elf {
+    fn new() -> Self {
+        Elf { items: Vec::new() }
+    }
+
+    pub fn get_total_calories(&self) -> u32 {
+        self.items.iter().map(|food| food.calories).sum()
+    }
+}
+
+// Any number of blank lines separate elves, so blank lines at the start or
+// end of the input don't make empty elves. Like `ElfTotals`, an elf can't
+// carry more than `u32::MAX` calories in total
+pub fn input_to_elves(input: &str) -> Result<Vec<Elf>> {
+    let mut elves: Vec<Elf> = Vec::new();
+
+    let mut in_elf = false; // whether the previous line was a calorie count
+    let mut elf_total: u32 = 0;
+    for (line_index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            in_elf = false;
+            continue;
+        }
+
+        let current_item_calories = line.parse::<u32>().map_err(|_| {
+            ParseError::in_line(line_index, line, line, "a calorie count or a blank line")
+        })?;
+
+        if !in_elf {
+            elves.push(Elf::new());
+            in_elf = true;
+            elf_total = 0;
+        }
+
+        elf_total = elf_total
+            .checked_add(current_item_calories)
+            .ok_or_else(|| {
+                ParseError::in_line(
+                    line_index,
+                    line,
+                    line,
+                    format!("an elf carrying at most {} calories", u32::MAX),
+                )
+            })?;
+
+        if let Some(current_elf) = elves.last_mut() {
+            current_elf.items.push(FoodItem {
+                calories: current_item_calories,
+            });
+        }
+    }
+
+    if elves.is_empty() {
+        return Err(ParseError::new("", "at least one calorie count"));
+    }
+
+    Ok(elves)
+}
+
+// Reads each elf's total calories straight from `reader`, for inputs too big
+// to hold in memory. One line buffer is reused for the whole input, so
+// nothing is allocated per item. Blank lines are handled like
+// `input_to_elves`, and lines can end in `\r\n`. Invalid lines are
+// `InvalidData` errors wrapping a `ParseError`
+pub struct ElfTotals<R> {
+    reader: R,
+    line: String,
+    line_index: usize,
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    pub fn new(reader: R) -> Self {
+        ElfTotals {
+            reader,
+            line: String::new(),
+            line_index: 0,
+        }
+    }
+
+    // the next line without its line ending, or None at the end of the input
+    fn next_line(&mut self) -> io::Result<Option<(usize, &str)>> {
+        self.line.clear();
+
+        if self.reader.read_line(&mut self.line)? == 0 {
+            return Ok(None);
+        }
+
+        let line_index = self.line_index;
+        self.line_index += 1;
+
+        Ok(Some((line_index, self.line.trim_end_matches(['\n', '\r']))))
+    }
+}
+
+fn invalid_data(error: ParseError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = io::Result<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total: Option<u32> = None;
+
+        loop {
+            let (line_index, line) = match self.next_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => return total.map(Ok),
+                Err(err) => return Some(Err(err)),
+            };
+
+            if line.is_empty() {
+                match total {
+                    Some(total) => return Some(Ok(total)),
+                    None => continue,
+                }
+            }
+
+            let calories = match line.parse::<u32>() {
+                Ok(calories) => calories,
+                Err(_) => {
+                    return Some(Err(invalid_data(ParseError::in_line(
+                        line_index,
+                        line,
+                        line,
+                        "a calorie count or a blank line",
+                    ))))
+                }
+            };
+
+            total = match total.unwrap_or(0).checked_add(calories) {
+                Some(total) => Some(total),
+                None => {
+                    return Some(Err(invalid_data(ParseError::in_line(
+                        line_index,
+                        line,
+                        line,
+                        format!("an elf carrying at most {} calories", u32::MAX),
+                    ))))
+                }
+            };
+        }
+    }
+}
+
+pub fn elf_with_most(elves: &[Elf]) -> &Elf {
+    elves
+        .iter()
+        .max_by_key(|elf| elf.get_total_calories())
+        .unwrap()
+}
+
+// An elf along with where it was in the list and its total calories
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RankedElf<'a> {
+    pub index: usize,
+    pub elf: &'a Elf,
+    pub total_calories: u32,
+}
+
+// The `n` elves carrying the most calories, most first, or every elf if there
+// are fewer than `n`. Ties go to the elf that comes first. Only the best `n`
+// are kept while going through the elves, so it takes O(len · log n)
+pub fn top_n_elves(elves: &[Elf], n: usize) -> Vec<RankedElf<'_>> {
+    // the smallest total is on top, ready to be pushed out by a bigger one.
+    // Among equal totals the later elf is on top
+    let mut top = BinaryHeap::with_capacity(n + 1);
+
+    for (index, elf) in elves.iter().enumerate() {
+        top.push(Reverse((elf.get_total_calories(), Reverse(index))));
+
+        if top.len() > n {
+            top.pop();
+        }
+    }
+
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total_calories, Reverse(index)))| RankedElf {
+            index,
+            elf: &elves[index],
+            total_calories,
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_to_elves(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        elf_with_most(elves).get_total_calories().into()
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        let top_3 = top_n_elves(elves, 3);
+
+        if top_3.len() < 3 {
+            return Answer::Failed(format!(
+                "the top 3 elves are needed, but there are only {}",
+                top_3.len()
+            ));
+        }
+
+        // three elves' totals can add up to more than a u32
+        let sum_of_top_3: u64 = top_3
+            .iter()
+            .map(|ranked| ranked.total_calories as u64)
+            .sum();
+
+        sum_of_top_3.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{elf_with_most, input_to_elves, top_n_elves, Day1, ElfTotals};
+    use common::{Answer, Solution};
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day1);
+
+    #[test]
+    fn it_works() {
+        let mut elves = input_to_elves(TEST_INPUT).unwrap();
+
+        let elf_with_most = elf_with_most(&elves);
+
+        assert_eq!(elf_with_most.get_total_calories(), 24000);
+
+        // sort the elves by calories
+        elves.sort_by_key(|elf| elf.get_total_calories());
+        elves.reverse();
+
+        let top_3_calories: Vec<u32> = elves[0..3]
+            .iter()
+            .map(|elf| elf.get_total_calories())
+            .collect();
+
+        let sum_of_top_3: u32 = top_3_calories.iter().sum();
+
+        assert_eq!(sum_of_top_3, 45000);
+    }
+
+    #[test]
+    fn test_solution() {
+        let elves = Day1::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(Day1::part1(&elves), Answer::from(24000u32));
+        assert_eq!(Day1::part2(&elves), Answer::from(45000u32));
+    }
+
+    #[test]
+    fn test_top_n_elves() {
+        let elves = input_to_elves(TEST_INPUT).unwrap();
+
+        let top_3 = top_n_elves(&elves, 3);
+
+        assert_eq!(
+            top_3
+                .iter()
+                .map(|ranked| (ranked.index, ranked.total_calories))
+                .collect::<Vec<_>>(),
+            vec![(3, 24000), (2, 11000), (4, 10000)]
+        );
+        assert_eq!(top_3[0].elf, &elves[3]);
+
+        assert_eq!(top_n_elves(&elves, 10).len(), 5);
+        assert!(top_n_elves(&elves, 0).is_empty());
+    }
+
+    #[test]
+    fn test_top_n_elves_ties() {
+        let elves = input_to_elves("300\n\n100\n200\n\n300\n\n50").unwrap();
+
+        let indices: Vec<usize> = top_n_elves(&elves, 2)
+            .iter()
+            .map(|ranked| ranked.index)
+            .collect();
+
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(top_n_elves(&elves, 3)[2].index, 2);
+    }
+
+    #[test]
+    fn test_fewer_than_three_elves() {
+        let elves = Day1::parse("1000\n\n2000").unwrap();
+
+        assert_eq!(Day1::part1(&elves), Answer::from(2000u32));
+        assert_eq!(
+            Day1::part2(&elves),
+            Answer::Failed(String::from(
+                "the top 3 elves are needed, but there are only 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_top_3_sum_past_u32() {
+        let elves = Day1::parse("4294967295\n\n4294967295\n\n4294967295").unwrap();
+
+        assert_eq!(Day1::part2(&elves), Answer::from(3 * u32::MAX as u64));
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let elves = input_to_elves("\n\n1000\n2000\n\n\n\n3000\n\n").unwrap();
+
+        let totals: Vec<u32> = elves.iter().map(|elf| elf.get_total_calories()).collect();
+
+        assert_eq!(totals, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn test_elf_totals() {
+        let totals: Vec<u32> = ElfTotals::new(TEST_INPUT.as_bytes())
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+
+        let expected: Vec<u32> = input_to_elves(TEST_INPUT)
+            .unwrap()
+            .iter()
+            .map(|elf| elf.get_total_calories())
+            .collect();
+
+        assert_eq!(totals, expected);
+    }
+
+    #[test]
+    fn test_elf_totals_blank_lines_and_crlf() {
+        let input = "\r\n\r\n1000\r\n2000\r\n\r\n\r\n3000\r\n\r\n";
+
+        let totals: Vec<u32> = ElfTotals::new(input.as_bytes())
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(totals, vec![3000, 3000]);
+
+        assert_eq!(ElfTotals::new("".as_bytes()).count(), 0);
+        assert_eq!(ElfTotals::new("\n\n".as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn test_elf_totals_errors() {
+        let mut totals = ElfTotals::new("1000\n\n30x0\r\n".as_bytes());
+
+        assert_eq!(totals.next().unwrap().unwrap(), 1000);
+
+        let error = totals.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected `30x0`, expected a calorie count or a blank line"
+        );
+
+        let error = ElfTotals::new("4294967295\n1".as_bytes())
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `1`, expected an elf carrying at most 4294967295 calories"
+        );
+    }
+
+    #[test]
+    fn test_parse_too_many_calories() {
+        let error = input_to_elves("4294967295\n\n4294967295\n1").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: unexpected `1`, expected an elf carrying at most 4294967295 calories"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = input_to_elves("1000\n2000\n\n30x0").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: unexpected `30x0`, expected a calorie count or a blank line"
+        );
+    }
+}
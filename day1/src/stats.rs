@@ -0,0 +1,316 @@
+use serde::Serialize;
+
+use crate::Elf;
+
+// the percentiles reported alongside the median
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+// Elves further than this many interquartile ranges outside the middle half
+// are outliers (Tukey's fences)
+const OUTLIER_IQRS: f64 = 1.5;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Percentile {
+    pub percentile: u8,
+    pub value: f64,
+}
+
+// How a value is spread across the elves
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Distribution {
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile>,
+    #[serde(skip)]
+    sorted: Vec<u32>,
+}
+
+impl Distribution {
+    // None if there are no values
+    fn new(mut values: Vec<u32>) -> Option<Self> {
+        values.sort_unstable();
+
+        let sum: u64 = values.iter().map(|value| *value as u64).sum();
+
+        Some(Distribution {
+            min: *values.first()?,
+            max: *values.last()?,
+            mean: sum as f64 / values.len() as f64,
+            median: percentile(&values, 50),
+            percentiles: PERCENTILES
+                .into_iter()
+                .map(|percentile_rank| Percentile {
+                    percentile: percentile_rank,
+                    value: percentile(&values, percentile_rank),
+                })
+                .collect(),
+            sorted: values,
+        })
+    }
+
+    // Any percentile, not just the reported ones. None if `percentile_rank`
+    // is over 100
+    pub fn percentile(&self, percentile_rank: u8) -> Option<f64> {
+        (percentile_rank <= 100).then(|| percentile(&self.sorted, percentile_rank))
+    }
+}
+
+// The value `percentile_rank` percent of the way through `sorted`,
+// interpolating between the values either side of it
+fn percentile(sorted: &[u32], percentile_rank: u8) -> f64 {
+    let rank = percentile_rank as f64 / 100.0 * (sorted.len() - 1) as f64;
+
+    let below = sorted[rank.floor() as usize] as f64;
+    let above = sorted[rank.ceil() as usize] as f64;
+
+    below + (above - below) * rank.fract()
+}
+
+// An elf carrying far more or far fewer calories than the rest
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Outlier {
+    pub index: usize,
+    pub total_calories: u32,
+}
+
+// A summary of how many calories the elves are carrying
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CalorieStats {
+    pub elves: usize,
+    pub calories: Distribution, // each elf's total
+    pub items: Distribution,    // the number of items each elf has
+    pub outliers: Vec<Outlier>,
+}
+
+impl CalorieStats {
+    // None if there are no elves
+    pub fn new(elves: &[Elf]) -> Option<Self> {
+        let totals: Vec<u32> = elves.iter().map(|elf| elf.get_total_calories()).collect();
+
+        let calories = Distribution::new(totals.clone())?;
+        let items = Distribution::new(elves.iter().map(|elf| elf.items.len() as u32).collect())?;
+
+        let lower_quartile = calories.percentile(25).expect("25 is a percentile");
+        let upper_quartile = calories.percentile(75).expect("75 is a percentile");
+        let fence = OUTLIER_IQRS * (upper_quartile - lower_quartile);
+
+        let outliers = totals
+            .into_iter()
+            .enumerate()
+            .filter(|(_, total)| {
+                let total = *total as f64;
+
+                total < lower_quartile - fence || total > upper_quartile + fence
+            })
+            .map(|(index, total_calories)| Outlier {
+                index,
+                total_calories,
+            })
+            .collect();
+
+        Some(CalorieStats {
+            elves: elves.len(),
+            calories,
+            items,
+            outliers,
+        })
+    }
+
+    // A table with a row per statistic and a column for calories and items,
+    // followed by the outliers
+    pub fn to_table(&self) -> String {
+        let mut rows = vec![
+            [
+                String::from("statistic"),
+                String::from("calories"),
+                String::from("items"),
+            ],
+            [
+                String::from("min"),
+                self.calories.min.to_string(),
+                self.items.min.to_string(),
+            ],
+            [
+                String::from("max"),
+                self.calories.max.to_string(),
+                self.items.max.to_string(),
+            ],
+            [
+                String::from("mean"),
+                format!("{:.1}", self.calories.mean),
+                format!("{:.1}", self.items.mean),
+            ],
+            [
+                String::from("median"),
+                format!("{:.1}", self.calories.median),
+                format!("{:.1}", self.items.median),
+            ],
+        ];
+
+        for (calories, items) in self
+            .calories
+            .percentiles
+            .iter()
+            .zip(&self.items.percentiles)
+        {
+            rows.push([
+                format!("p{}", calories.percentile),
+                format!("{:.1}", calories.value),
+                format!("{:.1}", items.value),
+            ]);
+        }
+
+        let widths: Vec<usize> = (0..3)
+            .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+            .collect();
+
+        let mut table = format!("elves: {}\n\n", self.elves);
+
+        for row in rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+                .collect();
+
+            table.push_str(&cells.join("  "));
+            table.push('\n');
+        }
+
+        let outliers: Vec<String> = self
+            .outliers
+            .iter()
+            .map(|outlier| {
+                format!(
+                    "elf {} ({} calories)",
+                    outlier.index, outlier.total_calories
+                )
+            })
+            .collect();
+
+        if outliers.is_empty() {
+            table.push_str("\noutliers: none\n");
+        } else {
+            table.push_str(&format!("\noutliers: {}\n", outliers.join(", ")));
+        }
+
+        table
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("stats only contain strings and numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_to_elves;
+    use crate::stats::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn test_percentile() {
+        let sorted = [4000, 6000, 10000, 11000, 24000];
+
+        assert_eq!(percentile(&sorted, 0), 4000.0);
+        assert_eq!(percentile(&sorted, 10), 4800.0);
+        assert_eq!(percentile(&sorted, 50), 10000.0);
+        assert_eq!(percentile(&sorted, 90), 18800.0);
+        assert_eq!(percentile(&sorted, 100), 24000.0);
+
+        assert_eq!(percentile(&[7], 75), 7.0);
+    }
+
+    #[test]
+    fn test_stats() {
+        let elves = input_to_elves(TEST_INPUT).unwrap();
+
+        let stats = CalorieStats::new(&elves).unwrap();
+
+        assert_eq!(stats.elves, 5);
+
+        assert_eq!(stats.calories.min, 4000);
+        assert_eq!(stats.calories.max, 24000);
+        assert_eq!(stats.calories.mean, 11000.0);
+        assert_eq!(stats.calories.median, 10000.0);
+        assert_eq!(stats.calories.percentile(25), Some(6000.0));
+        assert_eq!(stats.calories.percentile(75), Some(11000.0));
+        assert_eq!(stats.calories.percentile(60), Some(10400.0));
+        assert_eq!(stats.calories.percentile(101), None);
+
+        assert_eq!(stats.items.min, 1);
+        assert_eq!(stats.items.max, 3);
+        assert_eq!(stats.items.mean, 2.0);
+        assert_eq!(stats.items.median, 2.0);
+        assert_eq!(stats.items.percentile(0), Some(1.0));
+
+        // the upper fence is 11000 + 1.5 * 5000
+        assert_eq!(
+            stats.outliers,
+            vec![Outlier {
+                index: 3,
+                total_calories: 24000
+            }]
+        );
+    }
+
+    #[test]
+    fn test_no_elves() {
+        assert_eq!(CalorieStats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_to_table() {
+        let elves = input_to_elves(TEST_INPUT).unwrap();
+
+        let stats = CalorieStats::new(&elves).unwrap();
+
+        assert_eq!(
+            stats.to_table(),
+            "elves: 5
+
+statistic  calories  items
+      min      4000      1
+      max     24000      3
+     mean   11000.0    2.0
+   median   10000.0    2.0
+      p10    4800.0    1.0
+      p25    6000.0    1.0
+      p75   11000.0    3.0
+      p90   18800.0    3.0
+      p99   23480.0    3.0
+
+outliers: elf 3 (24000 calories)
+"
+        );
+
+        let stats = CalorieStats::new(&elves[..2]).unwrap();
+        assert!(stats.to_table().ends_with("\noutliers: none\n"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let elves = input_to_elves("1000\n2000\n\n3000").unwrap();
+
+        let stats = CalorieStats::new(&elves).unwrap();
+
+        assert_eq!(
+            stats.to_json(),
+            concat!(
+                r#"{"elves":2,"#,
+                r#""calories":{"min":3000,"max":3000,"mean":3000.0,"median":3000.0,"percentiles":["#,
+                r#"{"percentile":10,"value":3000.0},{"percentile":25,"value":3000.0},"#,
+                r#"{"percentile":75,"value":3000.0},{"percentile":90,"value":3000.0},"#,
+                r#"{"percentile":99,"value":3000.0}]},"#,
+                r#""items":{"min":1,"max":2,"mean":1.5,"median":1.5,"percentiles":["#,
+                r#"{"percentile":10,"value":1.1},{"percentile":25,"value":1.25},"#,
+                r#"{"percentile":75,"value":1.75},{"percentile":90,"value":1.9},"#,
+                r#"{"percentile":99,"value":1.99}]},"#,
+                r#""outliers":[]}"#
+            )
+        );
+    }
+}
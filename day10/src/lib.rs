@@ -0,0 +1,212 @@
+use std::collections::LinkedList;
+
+use common::{Answer, Grid, ParseError, Result, Solution};
+use log::trace;
+
+pub enum Operation {
+    Noop,
+    Add { value: i32 },
+}
+
+pub struct Instruction {
+    pub operation: Operation,
+    pub num_cycles: usize,
+}
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+impl Instruction {
+    pub fn from(line: &str) -> Result<Self> {
+        let (opcode, operand) = match line.split_once(' ') {
+            Some((opcode, operand)) => (opcode, Some(operand)),
+            None => (line, None),
+        };
+
+        match (opcode, operand) {
+            ("addx", Some(number)) if !number.contains(' ') => {
+                let parsed_number = number
+                    .parse()
+                    .map_err(|_| ParseError::in_piece(line, number, "a number to add"))?;
+
+                Ok(Instruction {
+                    operation: Operation::Add {
+                        value: parsed_number,
+                    },
+                    num_cycles: 2,
+                })
+            }
+            ("addx", _) => Err(ParseError::in_piece(
+                line,
+                line,
+                "a single operand for addx, like `addx -3`",
+            )),
+            ("noop", None) => Ok(Instruction {
+                operation: Operation::Noop,
+                num_cycles: 1,
+            }),
+            _ => Err(ParseError::in_piece(line, line, "`noop` or `addx`")),
+        }
+    }
+}
+
+fn input_to_instructions(input: &str) -> Result<Vec<Instruction>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| Instruction::from(line).map_err(|err| err.at(line_index, line)))
+        .collect()
+}
+
+fn part1(instructions: &[Instruction]) -> i32 {
+    let mut register: i32 = 1;
+
+    let mut current_cycle: usize = 0;
+
+    let mut cycles_to_check: LinkedList<usize> = LinkedList::from([20, 60, 100, 140, 180, 220]);
+    let mut next_cycle_check = cycles_to_check.pop_front().unwrap();
+
+    let mut signal_strengths = Vec::new();
+
+    for instruction in instructions {
+        if current_cycle + instruction.num_cycles >= next_cycle_check {
+            signal_strengths.push(register * next_cycle_check as i32);
+
+            next_cycle_check = match cycles_to_check.pop_front() {
+                Some(cycle) => cycle,
+                None => break,
+            }
+        }
+
+        current_cycle += instruction.num_cycles;
+
+        if let Operation::Add { value } = instruction.operation {
+            register += value
+        }
+    }
+
+    signal_strengths.iter().sum()
+}
+
+fn part2(instructions: &[Instruction]) -> Grid<char> {
+    let mut sprite_midpoint: i32 = 1;
+
+    let mut current_cycle: usize = 0;
+
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, '.');
+
+    for instruction in instructions {
+        for i in 1..=instruction.num_cycles {
+            let pixel_being_rendered = (current_cycle % SCREEN_WIDTH, current_cycle / SCREEN_WIDTH);
+
+            let sprite_pixels = (sprite_midpoint - 1)..=(sprite_midpoint + 1);
+
+            // anything drawn after the last row is off the screen
+            if let Some(pixel) = screen.get_mut(pixel_being_rendered) {
+                if sprite_pixels.contains(&(pixel_being_rendered.0 as i32)) {
+                    *pixel = '#';
+                }
+            }
+
+            current_cycle += 1;
+            match instruction.operation {
+                Operation::Add { value } if i == instruction.num_cycles => {
+                    sprite_midpoint += value;
+                }
+                _ => {}
+            }
+
+            trace!("sprite_midpoint: {}", sprite_midpoint);
+            trace!("\n{}", screen);
+        }
+    }
+
+    screen
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_to_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        part2(instructions).to_string().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input_to_instructions, part1};
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day10);
+
+    #[test]
+    fn test_part1() {
+        let instructions = input_to_instructions(TEST_INPUT).unwrap();
+
+        let result = part1(&instructions);
+
+        assert_eq!(result, 13140);
+    }
+
+    #[test]
+    fn test_part2() {
+        let instructions = input_to_instructions(TEST_INPUT).unwrap();
+
+        let screen = crate::part2(&instructions);
+
+        assert_eq!(
+            screen.to_string(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = input_to_instructions("noop\naddx 1x").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: unexpected `1x`, expected a number to add"
+        );
+
+        let error = input_to_instructions("noop\nmulx 3").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `mulx 3`, expected `noop` or `addx`"
+        );
+
+        let error = input_to_instructions("addx addx").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: unexpected `addx`, expected a number to add"
+        );
+
+        let error = input_to_instructions("addxyz 3").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: unexpected `addxyz 3`, expected `noop` or `addx`"
+        );
+
+        let error = input_to_instructions("noop 3").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: unexpected `noop 3`, expected `noop` or `addx`"
+        );
+    }
+}
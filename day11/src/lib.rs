@@ -0,0 +1,421 @@
+use std::{collections::LinkedList, rc::Rc};
+
+use common::{Answer, ParseError, Result, Solution};
+use log::{debug, info};
+
+// applied to an item's worry level when a monkey inspects it
+pub type Operation = Rc<dyn Fn(&u128) -> u128>;
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub items: LinkedList<u128>,
+    pub num_inspections: u128,
+    pub inspect_item: Operation,
+    pub test_number: u128, // is the worry level divisible by this amount?
+    pub target_monkey: Rc<dyn Fn(bool) -> usize>,
+    pub stress_management_number: u128, // multiply all of the divisors together, and use this to keep numbers from overflowing a 128 bit integer
+}
+
+impl Monkey {
+    pub fn is_empty_handed(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn inspect_items_in_hand_part1(&mut self) {
+        let new_worry_levels: LinkedList<u128> = self
+            .items
+            .iter()
+            .map(|worry_level| {
+                self.num_inspections += 1;
+                (self.inspect_item)(worry_level) / 3
+            })
+            .collect();
+
+        self.items = new_worry_levels;
+    }
+
+    pub fn inspect_items_in_hand_part2(&mut self) {
+        let new_worry_levels: LinkedList<u128> = self
+            .items
+            .iter()
+            .map(|worry_level| {
+                self.num_inspections += 1;
+                (self.inspect_item)(worry_level) % self.stress_management_number
+            })
+            .collect();
+
+        self.items = new_worry_levels;
+    }
+
+    pub fn determine_target_monkeys(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .map(|item_worry| self.test_item(*item_worry))
+            .map(|test_result| (self.target_monkey)(test_result))
+            .collect()
+    }
+
+    pub fn throw_items(&mut self) -> Vec<u128> {
+        let items: Vec<u128> = self.items.iter().copied().collect();
+
+        self.items = LinkedList::new();
+
+        items
+    }
+
+    pub fn catch_item(&mut self, item_worry: u128) {
+        self.items.push_back(item_worry);
+    }
+
+    pub fn test_item(&self, worry_level: u128) -> bool {
+        worry_level.is_multiple_of(self.test_number)
+    }
+}
+
+fn parse_starting_items(item_line: &str) -> Result<LinkedList<u128>> {
+    let input_split: Vec<&str> = item_line.split(':').collect();
+
+    let items = match &input_split[..] {
+        [label, items] if label.trim() == "Starting items" => items,
+        _ => {
+            return Err(ParseError::in_piece(
+                item_line,
+                item_line.trim(),
+                "a list of items like `Starting items: 79, 98`",
+            ))
+        }
+    };
+
+    items
+        .split(',')
+        .map(|worry_amount| worry_amount.trim())
+        .map(|worry_amount| {
+            worry_amount
+                .parse()
+                .map_err(|_| ParseError::in_piece(item_line, worry_amount, "a worry level"))
+        })
+        .collect()
+}
+
+fn parse_operation(operation_line: &str) -> Result<Operation> {
+    let input_split: Vec<&str> = operation_line.split("new = ").collect();
+
+    let operation_split: Vec<&str> = match &input_split[..] {
+        [_, operation] => operation.split(' ').collect(),
+        _ => {
+            return Err(ParseError::in_piece(
+                operation_line,
+                operation_line.trim(),
+                "an operation like `Operation: new = old * 19`",
+            ))
+        }
+    };
+
+    let (operator, operand) = if let ["old", operator, operand] = &operation_split[..] {
+        (operator, operand)
+    } else {
+        return Err(ParseError::in_piece(
+            operation_line,
+            input_split[1],
+            "an operation on `old`, like `old * 19`",
+        ));
+    };
+
+    let parse_operand = |operand: &str| {
+        operand
+            .parse::<u128>()
+            .map_err(|_| ParseError::in_piece(operation_line, operand, "`old` or a number"))
+    };
+
+    match (*operator, *operand) {
+        ("*", "old") => Ok(Rc::new(move |old| old * old)),
+        ("+", "old") => Ok(Rc::new(move |old| old + old)),
+        ("*", operand) => {
+            let parsed_operand = parse_operand(operand)?;
+            Ok(Rc::new(move |old| old * parsed_operand))
+        }
+        ("+", operand) => {
+            let parsed_operand = parse_operand(operand)?;
+            Ok(Rc::new(move |old| old + parsed_operand))
+        }
+        (operation, _) => Err(ParseError::in_piece(
+            operation_line,
+            operation,
+            "`*` or `+`",
+        )),
+    }
+}
+
+fn parse_test_number(test_line: &str) -> Result<u128> {
+    let input_split: Vec<&str> = test_line.split("divisible by ").collect();
+
+    let number = if let [_, number, ..] = &input_split[..] {
+        number
+    } else {
+        return Err(ParseError::in_piece(
+            test_line,
+            test_line.trim(),
+            "a test like `Test: divisible by 23`",
+        ));
+    };
+
+    match number.parse() {
+        Ok(0) | Err(_) => Err(ParseError::in_piece(
+            test_line,
+            number,
+            "a positive number to divide by",
+        )),
+        Ok(number) => Ok(number),
+    }
+}
+
+fn parse_target_number(target_line: &str) -> Result<usize> {
+    let target_split: Vec<&str> = target_line.split("throw to monkey").collect();
+
+    let target = if let [_, number, ..] = &target_split[..] {
+        number.trim()
+    } else {
+        return Err(ParseError::in_piece(
+            target_line,
+            target_line.trim(),
+            "a target like `If true: throw to monkey 2`",
+        ));
+    };
+
+    target.parse().map_err(|_| {
+        ParseError::in_piece(target_line, target, "the number of the monkey to throw to")
+    })
+}
+
+fn parse_target_monkey(true_target: usize, false_target: usize) -> Rc<dyn Fn(bool) -> usize> {
+    Rc::new(move |test_result| {
+        if test_result {
+            true_target
+        } else {
+            false_target
+        }
+    })
+}
+
+// number of non-empty lines describing each monkey
+const DESCRIPTION_LINES: usize = 6;
+
+fn initialize_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+
+    // targets of each monkey along with the lines they were parsed from
+    let mut targets = Vec::new();
+
+    let input_lines: Vec<(usize, &str)> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+
+    for chunk in input_lines[..].chunks(DESCRIPTION_LINES) {
+        if chunk.len() < DESCRIPTION_LINES {
+            let (last_index, _) = chunk[chunk.len() - 1];
+
+            return Err(ParseError::in_line(
+                last_index + 1,
+                "",
+                "",
+                "the rest of the monkey's description",
+            ));
+        }
+
+        // attach the location of the line a description came from
+        let parse_line = |index: usize| {
+            let (line_index, line) = chunk[index];
+
+            move |err: ParseError| err.at(line_index, line)
+        };
+
+        let (header_index, header) = chunk[0];
+        if !header.starts_with("Monkey") {
+            return Err(ParseError::in_line(
+                header_index,
+                header,
+                header,
+                "a monkey header like `Monkey 0:`",
+            ));
+        }
+
+        let true_target = parse_target_number(chunk[4].1).map_err(parse_line(4))?;
+        let false_target = parse_target_number(chunk[5].1).map_err(parse_line(5))?;
+
+        targets.push((chunk[4], true_target));
+        targets.push((chunk[5], false_target));
+
+        monkeys.push(Monkey {
+            items: parse_starting_items(chunk[1].1).map_err(parse_line(1))?,
+            num_inspections: 0,
+            inspect_item: parse_operation(chunk[2].1).map_err(parse_line(2))?,
+            test_number: parse_test_number(chunk[3].1).map_err(parse_line(3))?,
+            target_monkey: parse_target_monkey(true_target, false_target),
+            stress_management_number: 0, // will set after all have been parsed
+        });
+    }
+
+    for ((line_index, line), target) in targets {
+        if target >= monkeys.len() {
+            return Err(ParseError::in_line(
+                line_index,
+                line,
+                &target.to_string(),
+                format!("a monkey number below {}", monkeys.len()),
+            ));
+        }
+    }
+
+    let stress_management_number: u128 = monkeys.iter().map(|monkey| monkey.test_number).product();
+
+    for monkey in monkeys.iter_mut() {
+        monkey.stress_management_number = stress_management_number;
+    }
+
+    Ok(monkeys)
+}
+
+fn part1(monkeys: &[Monkey]) -> u128 {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..20 {
+        for monkey_index in 0..monkeys.len() {
+            let current_monkey = &mut monkeys[monkey_index];
+
+            if current_monkey.is_empty_handed() {
+                continue;
+            }
+
+            current_monkey.inspect_items_in_hand_part1();
+
+            let target_monkeys = current_monkey.determine_target_monkeys();
+            let items = current_monkey.throw_items();
+
+            for (target, item) in target_monkeys.iter().zip(items.iter()) {
+                monkeys[*target].catch_item(*item);
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.num_inspections));
+
+    monkeys
+        .iter()
+        .take(2)
+        .map(|monkey| {
+            debug!("monkey did {} inspections", monkey.num_inspections);
+            monkey.num_inspections
+        })
+        .product()
+}
+
+fn part2(monkeys: &[Monkey]) -> u128 {
+    let mut monkeys = monkeys.to_vec();
+
+    for iteration in 0..10_000 {
+        if iteration % 1_000 == 0 {
+            info!("reached iteration: {}", iteration)
+        }
+
+        for monkey_index in 0..monkeys.len() {
+            let current_monkey = &mut monkeys[monkey_index];
+
+            if current_monkey.is_empty_handed() {
+                continue;
+            }
+
+            current_monkey.inspect_items_in_hand_part2();
+
+            let target_monkeys = current_monkey.determine_target_monkeys();
+            let items = current_monkey.throw_items();
+
+            for (target, item) in target_monkeys.iter().zip(items.iter()) {
+                monkeys[*target].catch_item(*item);
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.num_inspections));
+
+    monkeys
+        .iter()
+        .take(2)
+        .map(|monkey| {
+            debug!("monkey did {} inspections", monkey.num_inspections);
+            monkey.num_inspections
+        })
+        .product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        initialize_monkeys(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        crate::part1(monkeys).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        crate::part2(monkeys).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    static TEST_STRING: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day11);
+
+    #[test]
+    fn test_part1() {
+        let monkeys = crate::initialize_monkeys(TEST_STRING).unwrap();
+
+        let monkey_business = crate::part1(&monkeys);
+
+        assert_eq!(monkey_business, 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        let monkeys = crate::initialize_monkeys(TEST_STRING).unwrap();
+
+        let monkey_business = crate::part2(&monkeys);
+
+        assert_eq!(monkey_business, 2713310158u128);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = TEST_STRING.replace("old * 19", "old / 19");
+        let error = crate::initialize_monkeys(&input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 24: unexpected `/`, expected `*` or `+`"
+        );
+
+        let input = TEST_STRING.replace("throw to monkey 1\n", "throw to monkey 4\n");
+        let error = crate::initialize_monkeys(&input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 19, column 30: unexpected `4`, expected a monkey number below 4"
+        );
+
+        let input = TEST_STRING
+            .lines()
+            .take(10)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let error = crate::initialize_monkeys(&input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 11, column 1: unexpected end of line, expected the rest of the monkey's description"
+        );
+    }
+}
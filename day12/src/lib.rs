@@ -0,0 +1,492 @@
+mod render;
+
+use common::{search, Answer, Grid, ParseError, Result, Solution};
+use log::{debug, info, log_enabled, Level};
+
+pub use common::Position;
+
+// How the heightmap can be moved around. The puzzle's rules are the default:
+// climb at most one higher, drop any distance, no diagonal moves, and every
+// move costs the same
+#[derive(Clone, Copy, Debug)]
+pub struct ClimbingRules {
+    pub max_climb: Option<u8>,   // unlimited when None
+    pub max_descent: Option<u8>, // unlimited when None
+    pub diagonal: bool,
+    pub cost: fn(i16) -> u32, // given the change in height
+}
+
+impl Default for ClimbingRules {
+    fn default() -> Self {
+        ClimbingRules {
+            max_climb: Some(1),
+            max_descent: None,
+            diagonal: false,
+            cost: |_| 1,
+        }
+    }
+}
+
+impl ClimbingRules {
+    pub fn allows(&self, from_height: u8, to_height: u8) -> bool {
+        let within = |limit: Option<u8>, change: u8| limit.is_none_or(|limit| change <= limit);
+
+        if to_height >= from_height {
+            within(self.max_climb, to_height - from_height)
+        } else {
+            within(self.max_descent, from_height - to_height)
+        }
+    }
+}
+
+// The result of looking for the shortest path to a target
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Route {
+    // `path` runs from the start to the target, including both, so it's one
+    // longer than the number of steps
+    Found { steps: usize, path: Vec<Position> },
+    Unreachable,
+}
+
+impl Route {
+    pub fn steps(&self) -> Option<usize> {
+        match self {
+            Route::Found { steps, .. } => Some(*steps),
+            Route::Unreachable => None,
+        }
+    }
+}
+
+pub struct Map {
+    heightmap: Grid<u8>,
+    rules: ClimbingRules,
+}
+
+impl Map {
+    pub fn with_rules(self, rules: ClimbingRules) -> Self {
+        Map { rules, ..self }
+    }
+
+    fn neighbors(&self, position: Position) -> Box<dyn Iterator<Item = Position> + '_> {
+        if self.rules.diagonal {
+            Box::new(self.heightmap.neighbors8(position))
+        } else {
+            Box::new(self.heightmap.neighbors4(position))
+        }
+    }
+
+    pub fn shortest_path(&self, start_position: Position, target_position: Position) -> Route {
+        let path = search::bfs(
+            start_position,
+            |position| self.get_potential_neighbors(*position),
+            |position| *position == target_position,
+        );
+
+        match path {
+            Some(path) => Route::Found {
+                steps: path.steps(),
+                path: path.nodes,
+            },
+            None => Route::Unreachable,
+        }
+    }
+
+    // The cheapest path under the rules' cost, which can take more steps than
+    // the shortest one. The path includes both the start and the target
+    pub fn cheapest_path(
+        &self,
+        start_position: Position,
+        target_position: Position,
+    ) -> Option<search::Path<Position, u32>> {
+        search::dijkstra(
+            start_position,
+            |position| self.get_potential_neighbors(*position),
+            |from, to| (self.rules.cost)(self.heightmap[*to] as i16 - self.heightmap[*from] as i16),
+            |position| *position == target_position,
+        )
+    }
+
+    // the neighbors the rules allow moving to. Clockwise starting from the top
+    pub fn get_potential_neighbors(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let current_height = self.heightmap[position];
+
+        self.neighbors(position)
+            .filter(move |neighbor| self.rules.allows(current_height, self.heightmap[*neighbor]))
+    }
+
+    // the neighbors that can move to `position`, for searching backwards from the target
+    fn get_potential_predecessors(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let current_height = self.heightmap[position];
+
+        self.neighbors(position)
+            .filter(move |neighbor| self.rules.allows(self.heightmap[*neighbor], current_height))
+    }
+
+    // The fewest steps from every position to the target, found with a single
+    // search backwards from it. Positions that can't reach the target are None
+    pub fn distances_to(&self, target: Position) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.heightmap.width(), self.heightmap.height(), None);
+
+        let found = search::distances(target, |position| {
+            self.get_potential_predecessors(*position)
+        });
+
+        for (position, steps) in found {
+            distances[position] = Some(steps);
+        }
+
+        distances
+    }
+
+    // every square that can be reached from `start`
+    pub fn reachable_from(&self, start: Position) -> Grid<bool> {
+        let mut reachable = Grid::new(self.heightmap.width(), self.heightmap.height(), false);
+
+        let found = search::distances(start, |position| self.get_potential_neighbors(*position));
+
+        for position in found.into_keys() {
+            reachable[position] = true;
+        }
+
+        reachable
+    }
+
+    pub fn lowest_points(&self) -> Vec<Position> {
+        let lowest_height = b'a';
+
+        self.heightmap
+            .iter()
+            .filter(|(_, height)| **height == lowest_height)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+// The position of the only square marked with `marker`. `name` describes the
+// square in errors, e.g. "start"
+fn find_marker(squares: &Grid<char>, marker: char, name: &str) -> Result<Position> {
+    let mut marked = squares
+        .iter()
+        .filter(|(_, square)| **square == marker)
+        .map(|(position, _)| position);
+
+    let position = marked
+        .next()
+        .ok_or_else(|| ParseError::new("", format!("a {} square marked `{}`", name, marker)))?;
+
+    if let Some((column, row)) = marked.next() {
+        return Err(ParseError::in_column(
+            row,
+            column,
+            &marker.to_string(),
+            format!("only one {} square", name),
+        ));
+    }
+
+    Ok(position)
+}
+
+fn parse_map(input: &str) -> Result<(Map, Position, Position)> {
+    let squares = Grid::parse(input, "squares", |square| match square {
+        'a'..='z' | 'S' | 'E' => Ok(square),
+        _ => Err(String::from("a lowercase letter, `S` or `E`")),
+    })?;
+
+    let start_position = find_marker(&squares, 'S', "start")?;
+    let target_position = find_marker(&squares, 'E', "target")?;
+
+    let heightmap = squares.map(|square| match square {
+        'S' => b'a',
+        'E' => b'z',
+        val => *val as u8,
+    });
+
+    let map = Map {
+        heightmap,
+        rules: ClimbingRules::default(),
+    };
+
+    Ok((map, start_position, target_position))
+}
+
+// None when no lowest square can reach the target
+fn fewest_steps_from_lowest(map: &Map, target_position: Position) -> Option<usize> {
+    let distances = map.distances_to(target_position);
+
+    map.lowest_points()
+        .into_iter()
+        .filter_map(|low_pos| distances[low_pos])
+        .min()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Map, Position, Position);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_map(input)
+    }
+
+    fn part1((map, start_pos, end_pos): &Self::Input) -> Answer {
+        match map.shortest_path(*start_pos, *end_pos) {
+            Route::Found { steps, path } => {
+                if log_enabled!(Level::Debug) {
+                    debug!("\n{}", map.render_path(&path));
+                }
+
+                steps.into()
+            }
+            Route::Unreachable => {
+                if log_enabled!(Level::Info) {
+                    info!(
+                        "squares reachable from `S` are marked `.`:\n{}",
+                        map.render_area(&map.reachable_from(*start_pos))
+                    );
+                }
+
+                Answer::Failed(String::from("`E` can't be reached from `S`"))
+            }
+        }
+    }
+
+    fn part2((map, _start_pos, end_pos): &Self::Input) -> Answer {
+        match fewest_steps_from_lowest(map, *end_pos) {
+            Some(steps) => steps.into(),
+            None => {
+                if log_enabled!(Level::Info) {
+                    let can_reach_target = map.distances_to(*end_pos).map(Option::is_some);
+
+                    info!(
+                        "squares that can reach `E` are marked `.`:\n{}",
+                        map.render_area(&can_reach_target)
+                    );
+                }
+
+                Answer::Failed(String::from(
+                    "`E` can't be reached from any of the lowest squares",
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TEST_STRING: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day12);
+
+    #[test]
+    fn test_part1() {
+        let input = String::from(TEST_STRING);
+        let (map, start_pos, end_pos) = parse_map(&input).unwrap();
+
+        let shortest_path = map.shortest_path(start_pos, end_pos);
+
+        assert_eq!(shortest_path.steps(), Some(31));
+
+        let Route::Found { path, .. } = shortest_path else {
+            panic!("no path from S to E");
+        };
+
+        assert_eq!(path.len(), 32);
+        assert_eq!(path.first(), Some(&start_pos));
+        assert_eq!(path.last(), Some(&end_pos));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = String::from(TEST_STRING);
+        let (map, _start_pos, end_pos) = parse_map(&input).unwrap();
+
+        let low_positions = map.lowest_points();
+
+        let fewest_steps = low_positions
+            .into_iter()
+            .filter_map(|low_pos| map.shortest_path(low_pos, end_pos).steps())
+            .min();
+
+        assert_eq!(fewest_steps, Some(29));
+        assert_eq!(fewest_steps_from_lowest(&map, end_pos), Some(29));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // nothing can climb from `c` up to `E`
+        let input = parse_map("SbcE").unwrap();
+
+        assert_eq!(input.0.shortest_path(input.1, input.2), Route::Unreachable);
+        assert_eq!(fewest_steps_from_lowest(&input.0, input.2), None);
+
+        assert!(matches!(Day12::part1(&input), Answer::Failed(_)));
+        assert!(matches!(Day12::part2(&input), Answer::Failed(_)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_map("Sabc\nabdE\nab").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected `ab`, expected a row of 4 squares, like the first row"
+        );
+
+        let error = parse_map("Sabc\nabD1\nabcE").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected `D`, expected a lowercase letter, `S` or `E`"
+        );
+
+        let error = parse_map("Sabc\nabcd").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid input, expected a target square marked `E`"
+        );
+
+        let error = parse_map("abcE").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid input, expected a start square marked `S`"
+        );
+
+        let error = parse_map("SabE\nabES").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: unexpected `S`, expected only one start square"
+        );
+
+        let error = parse_map("SEbE").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: unexpected `E`, expected only one target square"
+        );
+    }
+
+    #[test]
+    fn test_distances_to() {
+        let (map, start_pos, end_pos) = parse_map(TEST_STRING).unwrap();
+
+        let distances = map.distances_to(end_pos);
+
+        assert_eq!(distances[end_pos], Some(0));
+        assert_eq!(distances[start_pos], Some(31));
+        assert_eq!(
+            map.lowest_points()
+                .into_iter()
+                .filter_map(|low_pos| distances[low_pos])
+                .min(),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_distances_to_unreachable() {
+        // nothing can climb from `c` up to `E`
+        let (map, start_pos, end_pos) = parse_map("SbcE").unwrap();
+
+        let distances = map.distances_to(end_pos);
+
+        assert_eq!(distances[end_pos], Some(0));
+        assert_eq!(distances[start_pos], None);
+        assert_eq!(distances[(2, 0)], None);
+    }
+
+    #[test]
+    fn test_rules_allow() {
+        let rules = ClimbingRules::default();
+
+        assert!(rules.allows(b'a', b'b'));
+        assert!(!rules.allows(b'a', b'c'));
+        assert!(rules.allows(b'z', b'a'));
+
+        let rules = ClimbingRules {
+            max_climb: Some(2),
+            max_descent: Some(1),
+            ..ClimbingRules::default()
+        };
+
+        assert!(rules.allows(b'a', b'c'));
+        assert!(rules.allows(b'c', b'b'));
+        assert!(!rules.allows(b'c', b'a'));
+    }
+
+    #[test]
+    fn test_max_climb() {
+        let (map, start_pos, end_pos) = parse_map("SbcE").unwrap();
+
+        assert_eq!(map.shortest_path(start_pos, end_pos), Route::Unreachable);
+
+        let map = map.with_rules(ClimbingRules {
+            max_climb: None,
+            ..ClimbingRules::default()
+        });
+
+        assert_eq!(map.shortest_path(start_pos, end_pos).steps(), Some(3));
+    }
+
+    #[test]
+    fn test_max_descent() {
+        let (map, start_pos, end_pos) = parse_map("SzaE").unwrap();
+
+        let map = map.with_rules(ClimbingRules {
+            max_climb: None,
+            max_descent: Some(1),
+            ..ClimbingRules::default()
+        });
+
+        assert_eq!(map.shortest_path(start_pos, end_pos), Route::Unreachable);
+        assert_eq!(map.distances_to(end_pos)[start_pos], None);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let (map, start_pos, end_pos) = parse_map("Saa\naaa\naaE").unwrap();
+
+        let rules = ClimbingRules {
+            max_climb: None,
+            ..ClimbingRules::default()
+        };
+
+        let map = map.with_rules(rules);
+        assert_eq!(map.shortest_path(start_pos, end_pos).steps(), Some(4));
+
+        let map = map.with_rules(ClimbingRules {
+            diagonal: true,
+            ..rules
+        });
+        assert_eq!(map.shortest_path(start_pos, end_pos).steps(), Some(2));
+        assert_eq!(map.distances_to(end_pos)[start_pos], Some(2));
+    }
+
+    #[test]
+    fn test_cost() {
+        // going over the `z` is the fewest steps, but dropping back down is expensive
+        let (map, start_pos, end_pos) = parse_map("SzaE\naaaa").unwrap();
+
+        let map = map.with_rules(ClimbingRules {
+            max_climb: None,
+            cost: |change| {
+                if change < 0 {
+                    1 + 10 * -change as u32
+                } else {
+                    1
+                }
+            },
+            ..ClimbingRules::default()
+        });
+
+        assert_eq!(map.shortest_path(start_pos, end_pos).steps(), Some(3));
+
+        let cheapest = map.cheapest_path(start_pos, end_pos).unwrap();
+        assert_eq!(cheapest.steps(), 5);
+        assert_eq!(cheapest.cost, 5);
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt::Write;
+
+use common::{Direction, Grid, Position};
+
+use crate::Map;
+
+// how many pixels wide and tall each square is in PPM images
+const SQUARE_PIXELS: usize = 4;
+
+// SVGs scale without blurring, so their squares can be big enough to hover
+// over and read the height
+const SVG_SQUARE_PIXELS: usize = 16;
+
+const PATH_COLOR: (u8, u8, u8) = (220, 40, 40);
+
+// Low squares are dark green, fading to white at the peaks
+fn height_color(height: u8) -> (u8, u8, u8) {
+    let fraction = height.saturating_sub(b'a').min(25) as f32 / 25.0;
+
+    let blend = |low: f32, high: f32| (low + (high - low) * fraction) as u8;
+
+    (blend(20.0, 240.0), blend(70.0, 240.0), blend(30.0, 240.0))
+}
+
+// the arrow for a step from one square to the next
+fn arrow(from: Position, to: Position) -> char {
+    let step = (
+        to.0 as isize - from.0 as isize,
+        to.1 as isize - from.1 as isize,
+    );
+
+    match Direction::ALL
+        .into_iter()
+        .find(|direction| direction.offset() == step)
+    {
+        Some(Direction::Up) => '^',
+        Some(Direction::Right) => '>',
+        Some(Direction::Down) => 'v',
+        Some(Direction::Left) => '<',
+        Some(Direction::UpRight | Direction::DownLeft) => '/',
+        Some(Direction::DownRight | Direction::UpLeft) => '\\',
+        None => '?', // not a step to a neighbor
+    }
+}
+
+impl Map {
+    // Draws the heightmap with an arrow on each square of the path pointing
+    // to the next one, and the end of the path marked `E`. `path` includes
+    // both ends
+    pub fn render_path(&self, path: &[Position]) -> String {
+        let mut squares = self.heightmap.map(|height| *height as char);
+
+        for step in path.windows(2) {
+            squares[step[0]] = arrow(step[0], step[1]);
+        }
+
+        if let Some(end) = path.last() {
+            squares[*end] = 'E';
+        }
+
+        squares.to_string()
+    }
+
+    // Draws the heightmap with the squares in `area` as `.`, e.g. those
+    // reachable from the start. The heights left around its edge are the ones
+    // that couldn't be climbed
+    pub fn render_area(&self, area: &Grid<bool>) -> String {
+        let mut squares = self.heightmap.map(|height| *height as char);
+
+        for (position, in_area) in area.iter() {
+            if *in_area {
+                squares[position] = '.';
+            }
+        }
+
+        squares.to_string()
+    }
+
+    // An SVG of the heightmap coloured by height, with the path drawn over it
+    pub fn to_svg(&self, path: &[Position]) -> String {
+        let square = SVG_SQUARE_PIXELS;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+            self.heightmap.width() * square,
+            self.heightmap.height() * square
+        );
+
+        for ((column, row), height) in self.heightmap.iter() {
+            let (red, green, blue) = height_color(*height);
+
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"rgb({},{},{})\"><title>{}</title></rect>",
+                column * square,
+                row * square,
+                square,
+                square,
+                red,
+                green,
+                blue,
+                *height as char
+            )
+            .unwrap();
+        }
+
+        let points: Vec<String> = path
+            .iter()
+            .map(|(column, row)| {
+                format!(
+                    "{},{}",
+                    column * square + square / 2,
+                    row * square + square / 2
+                )
+            })
+            .collect();
+
+        let (red, green, blue) = PATH_COLOR;
+
+        writeln!(
+            svg,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"rgb({},{},{})\" stroke-width=\"{}\"/>",
+            points.join(" "),
+            red,
+            green,
+            blue,
+            square / 4
+        )
+        .unwrap();
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    // A binary PPM image of the heightmap coloured by height, with the squares
+    // on the path highlighted
+    pub fn to_ppm(&self, path: &[Position]) -> Vec<u8> {
+        let width = self.heightmap.width() * SQUARE_PIXELS;
+        let height = self.heightmap.height() * SQUARE_PIXELS;
+
+        let mut on_path = Grid::new(self.heightmap.width(), self.heightmap.height(), false);
+        for position in path {
+            on_path[*position] = true;
+        }
+
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for y in 0..height {
+            for x in 0..width {
+                let position = (x / SQUARE_PIXELS, y / SQUARE_PIXELS);
+
+                let (red, green, blue) = if on_path[position] {
+                    PATH_COLOR
+                } else {
+                    height_color(self.heightmap[position])
+                };
+
+                ppm.extend([red, green, blue]);
+            }
+        }
+
+        ppm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+    use crate::*;
+
+    static TEST_STRING: &str = include_str!("../examples/1.txt");
+
+    static TEST_PATH: [Position; 5] = [(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)];
+
+    #[test]
+    fn test_render_path() {
+        let (map, _, _) = parse_map(TEST_STRING).unwrap();
+
+        assert_eq!(
+            map.render_path(&TEST_PATH),
+            "vaEqponm
+>>^ryxxl
+accszzxk
+acctuvwj
+abdefghi"
+        );
+    }
+
+    #[test]
+    fn test_render_found_path() {
+        let (map, start_pos, end_pos) = parse_map("SbcdE").unwrap();
+
+        let map = map.with_rules(ClimbingRules {
+            max_climb: None,
+            ..ClimbingRules::default()
+        });
+
+        let path = map.cheapest_path(start_pos, end_pos).unwrap();
+
+        assert_eq!(map.render_path(&path.nodes), ">>>>E");
+    }
+
+    #[test]
+    fn test_render_area() {
+        // nothing can climb from `c` up to `E`
+        let (map, start_pos, end_pos) = parse_map("Sbc\nfdE").unwrap();
+
+        assert_eq!(map.render_area(&map.reachable_from(start_pos)), "...\nfdz");
+        assert_eq!(
+            map.render_area(&map.distances_to(end_pos).map(Option::is_some)),
+            "abc\nfd."
+        );
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let (map, _, _) = parse_map(TEST_STRING).unwrap();
+
+        let svg = map.to_svg(&TEST_PATH);
+
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"128\" height=\"80\">"));
+        assert_eq!(svg.matches("<rect").count(), 40);
+        assert!(svg.contains("<polyline points=\"8,8 8,24 24,24 40,24 40,8\""));
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let (map, _, _) = parse_map(TEST_STRING).unwrap();
+
+        let ppm = map.to_ppm(&TEST_PATH);
+
+        let header = b"P6\n32 20\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 32 * 20 * 3);
+
+        let pixel = |x: usize, y: usize| {
+            let start = header.len() + (y * 32 + x) * 3;
+            (ppm[start], ppm[start + 1], ppm[start + 2])
+        };
+
+        assert_eq!(pixel(0, 0), PATH_COLOR);
+        assert_eq!(pixel(4 * 7, 0), height_color(b'm'));
+    }
+}
@@ -0,0 +1,28 @@
+use common::{Answer, Result, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<String>;
+
+    // Every line as it is, including the blank lines that separate sections
+    // in some puzzles
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Once examples/1.txt and its answers in examples/1.part1.txt and
+    // examples/1.part2.txt are added, drop `unsolved` to check them
+    common::example_tests!(crate::Day13, unsolved);
+}
@@ -1,13 +0,0 @@
-fn main() {}
-
-#[cfg(test)]
-mod tests {
-    static TEST_STRING: &str = "
-    ";
-
-    #[test]
-    fn test_part1() {}
-
-    #[test]
-    fn test_part2() {}
-}
@@ -0,0 +1,242 @@
+use common::{Answer, ParseError, Solution};
+
+#[derive(Clone, Copy)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Choice {
+    pub fn score(&self) -> u32 {
+        match self {
+            Choice::Rock => 1,
+            Choice::Paper => 2,
+            Choice::Scissors => 3,
+        }
+    }
+
+    pub fn encrypted_to_tuple(col1: &str, col2: &str) -> Option<(Self, Self)> {
+        let choice1 = match col1 {
+            "A" => Choice::Rock,
+            "B" => Choice::Paper,
+            "C" => Choice::Scissors,
+            _ => return None,
+        };
+
+        let choice2 = match col2 {
+            "X" => Choice::Rock,
+            "Y" => Choice::Paper,
+            "Z" => Choice::Scissors,
+            _ => return None,
+        };
+
+        Some((choice1, choice2))
+    }
+
+    pub fn encrypted_to_tuple_pt2(col1: &str, col2: &str) -> Option<(Self, Self)> {
+        let their_choice = match col1 {
+            "A" => Choice::Rock,
+            "B" => Choice::Paper,
+            "C" => Choice::Scissors,
+            _ => return None,
+        };
+
+        let result = match col2 {
+            "X" => Result::Lose,
+            "Y" => Result::Draw,
+            "Z" => Result::Win,
+            _ => return None,
+        };
+
+        let my_choice = match (result, their_choice) {
+            (Result::Win, Choice::Rock) => Choice::Paper,
+            (Result::Win, Choice::Paper) => Choice::Scissors,
+            (Result::Win, Choice::Scissors) => Choice::Rock,
+            (Result::Lose, Choice::Rock) => Choice::Scissors,
+            (Result::Lose, Choice::Paper) => Choice::Rock,
+            (Result::Lose, Choice::Scissors) => Choice::Paper,
+            (Result::Draw, their_choice) => their_choice,
+        };
+
+        Some((their_choice, my_choice))
+    }
+}
+
+pub enum Result {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Result {
+    pub fn score(&self) -> u32 {
+        match self {
+            Result::Win => 6,
+            Result::Draw => 3,
+            Result::Lose => 0,
+        }
+    }
+}
+
+pub struct Round {
+    their_choice: Choice,
+    my_choice: Choice,
+}
+
+impl Round {
+    pub fn get_result(&self) -> Result {
+        use crate::Choice::{Paper, Rock, Scissors};
+
+        match (&self.their_choice, &self.my_choice) {
+            (Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => Result::Win,
+            (Paper, Rock) | (Scissors, Paper) | (Rock, Scissors) => Result::Lose,
+            _ => Result::Draw,
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.my_choice.score() + self.get_result().score()
+    }
+}
+
+// The two columns of a line in the strategy guide. What the second column
+// means depends on which part is being solved
+pub type EncryptedRound = (String, String);
+
+fn input_to_columns(input: &str) -> common::Result<Vec<EncryptedRound>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| {
+            let columns: Vec<&str> = line.split(' ').collect();
+
+            let (col1, col2) = match columns[..] {
+                [col1, col2] => (col1, col2),
+                [_, _, extra, ..] => {
+                    return Err(ParseError::in_line(
+                        line_index,
+                        line,
+                        extra,
+                        "exactly two columns",
+                    ))
+                }
+                _ => return Err(ParseError::in_line(line_index, line, "", "two columns")),
+            };
+
+            if !matches!(col1, "A" | "B" | "C") {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    col1,
+                    "`A`, `B` or `C`",
+                ));
+            }
+
+            if !matches!(col2, "X" | "Y" | "Z") {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    col2,
+                    "`X`, `Y` or `Z`",
+                ));
+            }
+
+            Ok((String::from(col1), String::from(col2)))
+        })
+        .collect()
+}
+
+fn decrypt_rounds(
+    columns: &[EncryptedRound],
+    decrypt: fn(&str, &str) -> Option<(Choice, Choice)>,
+) -> Vec<Round> {
+    columns
+        .iter()
+        .map(|(col1, col2)| decrypt(col1, col2).expect("columns are validated while parsing"))
+        .map(|(their_pick, my_pick)| Round {
+            their_choice: their_pick,
+            my_choice: my_pick,
+        })
+        .collect()
+}
+
+fn input_to_rounds(columns: &[EncryptedRound]) -> Vec<Round> {
+    decrypt_rounds(columns, Choice::encrypted_to_tuple)
+}
+
+fn input_to_rounds_pt2(columns: &[EncryptedRound]) -> Vec<Round> {
+    decrypt_rounds(columns, Choice::encrypted_to_tuple_pt2)
+}
+
+fn total_score(rounds: &[Round]) -> u32 {
+    rounds.iter().map(|round| round.score()).sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<EncryptedRound>;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        input_to_columns(input)
+    }
+
+    fn part1(columns: &Self::Input) -> Answer {
+        total_score(&input_to_rounds(columns)).into()
+    }
+
+    fn part2(columns: &Self::Input) -> Answer {
+        total_score(&input_to_rounds_pt2(columns)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day2);
+
+    #[test]
+    fn part1() {
+        let rounds = input_to_rounds(&input_to_columns(TEST_INPUT).unwrap());
+
+        let total_score: u32 = rounds.iter().map(|round| round.score()).sum();
+
+        assert_eq!(total_score, 15);
+    }
+
+    #[test]
+    fn part2() {
+        let rounds = input_to_rounds_pt2(&input_to_columns(TEST_INPUT).unwrap());
+
+        let total_score: u32 = rounds.iter().map(|round| round.score()).sum();
+
+        assert_eq!(total_score, 12);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = input_to_columns("A Y\nB W").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected `W`, expected `X`, `Y` or `Z`"
+        );
+
+        let error = input_to_columns("A Y\nB").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected end of line, expected two columns"
+        );
+
+        // the bad column repeats the first one
+        let error = input_to_columns("A A").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: unexpected `A`, expected `X`, `Y` or `Z`"
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use common::{Answer, ParseError, Result, Solution};
+use log::{debug, trace};
+
+#[derive(Debug)]
+pub struct Rucksack {
+    first_compartment: HashSet<char>,
+    second_compartment: HashSet<char>,
+}
+
+impl Rucksack {
+    pub fn from(line: &str) -> Self {
+        let chars: Vec<char> = line.chars().collect();
+
+        let total_items = chars.len();
+
+        let mut first_compartment = HashSet::new();
+        let mut second_compartment = HashSet::new();
+
+        for (index, item) in chars.iter().enumerate() {
+            if index < (total_items / 2) {
+                first_compartment.insert(*item);
+            } else {
+                second_compartment.insert(*item);
+            }
+        }
+
+        Rucksack {
+            first_compartment,
+            second_compartment,
+        }
+    }
+
+    pub fn get_all_items(&self) -> HashSet<char> {
+        HashSet::from_iter(
+            self.first_compartment
+                .union(&self.second_compartment)
+                .cloned(),
+        )
+    }
+
+    pub fn get_common_item(&self) -> Option<&char> {
+        self.first_compartment
+            .intersection(&self.second_compartment)
+            .next()
+    }
+}
+
+fn calculate_priority(item: &char) -> u16 {
+    match item {
+        'a' => 1,
+        'a'..='z' => (*item as u16) - ('a' as u16) + 1,
+        'A' => 27,
+        'A'..='Z' => (*item as u16) - ('A' as u16) + 27,
+        _ => panic!("unsupported character"),
+    }
+}
+
+fn input_to_rucksacks(input: &str) -> Result<Vec<Rucksack>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| {
+            if let Some(item) = line.chars().find(|item| !item.is_ascii_alphabetic()) {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    &item.to_string(),
+                    "an item from `a` to `z` or `A` to `Z`",
+                ));
+            }
+
+            if line.len() % 2 != 0 {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    line,
+                    "an even number of items to split between the compartments",
+                ));
+            }
+
+            let rucksack = Rucksack::from(line);
+
+            if rucksack.get_common_item().is_none() {
+                return Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    line,
+                    "an item in both compartments",
+                ));
+            }
+
+            Ok(rucksack)
+        })
+        .collect()
+}
+
+// The sum of the priorities of each group of three's badge, the item all
+// three carry, or why the rucksacks can't be grouped
+fn part2(rucksacks: &[Rucksack]) -> std::result::Result<u16, String> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(format!(
+            "{} rucksacks can't be split into groups of 3",
+            rucksacks.len()
+        ));
+    }
+
+    let mut group_start_index = 0;
+    let mut group_end_index = 2;
+
+    let mut group_priorities = Vec::new();
+
+    while group_end_index < rucksacks.len() {
+        let group = &rucksacks[group_start_index..=group_end_index];
+
+        trace!(
+            "group: {:?}",
+            group
+                .iter()
+                .map(|rucksack| rucksack.get_all_items())
+                .collect::<Vec<_>>()
+        );
+
+        let common_set = group
+            .iter()
+            .map(|rucksack| rucksack.get_all_items())
+            .reduce(|acc, other| HashSet::from_iter(acc.intersection(&other).cloned()))
+            .unwrap();
+
+        trace!("common_set: {:?}", common_set);
+
+        // should only be one element
+        let common_item = common_set.iter().next().ok_or_else(|| {
+            format!(
+                "no item is in all of rucksacks {} to {}",
+                group_start_index + 1,
+                group_end_index + 1
+            )
+        })?;
+
+        group_priorities.push(calculate_priority(common_item));
+
+        group_start_index += 3;
+        group_end_index += 3;
+    }
+
+    let priority_sum: u16 = group_priorities.iter().sum();
+
+    debug!("part 2 sum: {}", priority_sum);
+
+    Ok(priority_sum)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_to_rucksacks(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        let score: u16 = rucksacks
+            .iter()
+            .map(|rucksack| {
+                rucksack
+                    .get_common_item()
+                    .expect("parsing checks every rucksack has a common item")
+            })
+            .map(calculate_priority)
+            .sum();
+
+        score.into()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        match part2(rucksacks) {
+            Ok(priority_sum) => priority_sum.into(),
+            Err(reason) => Answer::Failed(reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day3);
+
+    #[test]
+    fn test_part1() {
+        let rucksacks = input_to_rucksacks(TEST_INPUT).unwrap();
+
+        for rucksack in &rucksacks {
+            println!("First compartment: {:?}", rucksack.first_compartment);
+            println!("Second compartment: {:?}", rucksack.second_compartment);
+            println!("Common item: {:?}", rucksack.get_common_item());
+            println!();
+        }
+
+        let score: u16 = rucksacks
+            .iter()
+            .map(|rucksack| rucksack.get_common_item().unwrap())
+            .map(calculate_priority)
+            .sum();
+
+        assert_eq!(score, 157)
+    }
+
+    #[test]
+    fn test_part2() {
+        let rucksacks = input_to_rucksacks(TEST_INPUT).unwrap();
+
+        let sum = part2(&rucksacks);
+
+        assert_eq!(sum, Ok(70));
+    }
+
+    #[test]
+    fn test_part2_bad_groups() {
+        let rucksacks = input_to_rucksacks(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            part2(&rucksacks[..4]),
+            Err(String::from("4 rucksacks can't be split into groups of 3"))
+        );
+
+        let rucksacks = input_to_rucksacks("abca\nabcb\nxyzx").unwrap();
+
+        assert_eq!(
+            part2(&rucksacks),
+            Err(String::from("no item is in all of rucksacks 1 to 3"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = input_to_rucksacks("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNq1jqzjG").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: unexpected `1`, expected an item from `a` to `z` or `A` to `Z`"
+        );
+
+        let error = input_to_rucksacks("vJrwpWtwJgWrhcsFMMfFFhFp\nabcd").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `abcd`, expected an item in both compartments"
+        );
+
+        let error = input_to_rucksacks("abc").unwrap_err();
+        assert_eq!(
+            error.location,
+            Some(common::Location { line: 1, column: 1 })
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use common::{Answer, ParseError, Result, Solution};
+use log::trace;
+
+#[derive(Debug)]
+pub struct SectionAssignment {
+    begin_section: u32,
+    end_section: u32,
+}
+
+impl SectionAssignment {
+    // Given input string of "1-2", return a SectionAssignment with begin: 1, end: 2
+    pub fn from_input_string(input: &str) -> Result<Self> {
+        let input_chars: Vec<&str> = input.split('-').collect();
+
+        trace!("{:?}", input_chars);
+
+        let parse_section = |section: &str| {
+            section.parse::<u32>().map_err(|_| {
+                // point at the whole assignment when the section is missing
+                let text = if section.is_empty() { input } else { section };
+
+                ParseError::in_piece(input, text, "a section number")
+            })
+        };
+
+        if let [begin_section, end_section] = &input_chars[..] {
+            let assignment = SectionAssignment {
+                begin_section: parse_section(begin_section)?,
+                end_section: parse_section(end_section)?,
+            };
+
+            if assignment.begin_section > assignment.end_section {
+                return Err(ParseError::in_piece(
+                    input,
+                    input,
+                    "a range whose first section isn't after its last",
+                ));
+            }
+
+            Ok(assignment)
+        } else {
+            Err(ParseError::in_piece(
+                input,
+                input,
+                "a range of two sections separated by a hyphen, like `2-4`",
+            ))
+        }
+    }
+
+    pub fn fully_contains(&self, other_assignment: &SectionAssignment) -> bool {
+        self.begin_section <= other_assignment.begin_section
+            && self.end_section >= other_assignment.end_section
+    }
+
+    pub fn overlaps(&self, other_assignment: &SectionAssignment) -> bool {
+        let other_begin_in_range = other_assignment.begin_section >= self.begin_section
+            && other_assignment.begin_section <= self.end_section;
+        let other_end_in_range = other_assignment.end_section >= self.begin_section
+            && other_assignment.end_section <= self.end_section;
+        let other_fully_contains = other_assignment.fully_contains(self);
+
+        other_begin_in_range || other_end_in_range || other_fully_contains
+    }
+}
+
+fn input_to_pairs(input: &str) -> Result<Vec<(SectionAssignment, SectionAssignment)>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| {
+            let assignments: Vec<&str> = line.split(',').collect();
+
+            if let [first_assignment, second_assignment] = &assignments[..] {
+                Ok((
+                    SectionAssignment::from_input_string(first_assignment)
+                        .map_err(|err| err.at(line_index, line))?,
+                    SectionAssignment::from_input_string(second_assignment).map_err(|err| {
+                        err.at_offset(line_index, line, first_assignment.len() + 1)
+                    })?,
+                ))
+            } else {
+                Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    line,
+                    "two section assignments separated by a comma",
+                ))
+            }
+        })
+        .collect()
+}
+
+fn total_fully_overlapping(assignments: &[(SectionAssignment, SectionAssignment)]) -> u32 {
+    assignments
+        .iter()
+        .map(|(assignment1, assignment2)| {
+            if assignment1.fully_contains(assignment2) || assignment2.fully_contains(assignment1) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn total_partially_overlapping(assignments: &[(SectionAssignment, SectionAssignment)]) -> u32 {
+    assignments
+        .iter()
+        .map(|(assignment1, assignment2)| {
+            if assignment1.overlaps(assignment2) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(SectionAssignment, SectionAssignment)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_to_pairs(input)
+    }
+
+    fn part1(assignments: &Self::Input) -> Answer {
+        total_fully_overlapping(assignments).into()
+    }
+
+    fn part2(assignments: &Self::Input) -> Answer {
+        total_partially_overlapping(assignments).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day4);
+
+    #[test]
+    fn test_part1() {
+        let assignments = input_to_pairs(TEST_INPUT).unwrap();
+
+        let total_overlapping = total_fully_overlapping(&assignments);
+
+        println!("Total overlapping: {}", total_overlapping);
+
+        assert_eq!(total_overlapping, 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let assignments = input_to_pairs(TEST_INPUT).unwrap();
+
+        let total_overlapping = total_partially_overlapping(&assignments);
+
+        println!("Total overlapping: {}", total_overlapping);
+
+        assert_eq!(total_overlapping, 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = input_to_pairs("2-4,6-8\n2-3,4-x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: unexpected `x`, expected a section number"
+        );
+
+        let error = input_to_pairs("2-4,6-8-9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: unexpected `6-8-9`, expected a range of two sections separated by a hyphen, like `2-4`"
+        );
+
+        let error = input_to_pairs("2-4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: unexpected `2-4`, expected two section assignments separated by a comma"
+        );
+
+        // the assignment missing a section repeats the start of the first one
+        let error = input_to_pairs("3-3,3-").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: unexpected `3-`, expected a section number"
+        );
+
+        let error = input_to_pairs("2-4,4-2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: unexpected `4-2`, expected a range whose first section isn't after its last"
+        );
+    }
+}
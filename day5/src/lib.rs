@@ -0,0 +1,317 @@
+use std::collections::LinkedList;
+
+use common::{Answer, ParseError, Result, Solution};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct Command {
+    num_to_move: usize,
+    from_stack: usize,
+    to_stack: usize,
+}
+
+impl Command {
+    // Given a line of the form:
+    // "move n from x to y", parse a Command where
+    // num_to_move = n, from_stack = x, to_stack = y
+    // Lines are paired with their index in the input, for error reporting
+    pub fn from_input_lines(lines: &[(usize, &str)]) -> Result<Vec<Command>> {
+        let pattern = Regex::new(
+            r"^move (?P<num_to_move>\d+) from (?P<from_stack>\d+) to (?P<to_stack>\d+)$",
+        )
+        .unwrap();
+
+        lines
+            .iter()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_index, line)| {
+                let matches = pattern.captures(line).ok_or_else(|| {
+                    ParseError::in_line(
+                        *line_index,
+                        line,
+                        line,
+                        "a command like `move 1 from 2 to 1`",
+                    )
+                })?;
+
+                // the pattern guarantees every group is present and made of digits
+                let parse_number = |name: &str| {
+                    let number = matches.name(name).unwrap().as_str();
+
+                    number.parse::<usize>().map_err(|_| {
+                        ParseError::in_line(*line_index, line, number, "a smaller number")
+                    })
+                };
+
+                Ok(Command {
+                    num_to_move: parse_number("num_to_move")?,
+                    from_stack: parse_number("from_stack")?,
+                    to_stack: parse_number("to_stack")?,
+                })
+            })
+            .collect()
+    }
+}
+
+pub type Stacks = Vec<LinkedList<char>>;
+pub type Commands = Vec<Command>;
+
+fn parse_input(lines: &str) -> Result<(Stacks, Commands)> {
+    let lines = lines.lines().enumerate();
+
+    let mut parsing_initial_state = true;
+    let mut initial_state_lines = Vec::new();
+    let mut command_lines = Vec::new();
+
+    for (line_index, line) in lines {
+        if line.is_empty() {
+            parsing_initial_state = false;
+        } else if parsing_initial_state {
+            initial_state_lines.push((line_index, line));
+        } else {
+            command_lines.push((line_index, line));
+        }
+    }
+
+    let initial_state: Stacks = build_stacks(&initial_state_lines)?;
+
+    let commands: Commands = Command::from_input_lines(&command_lines)?;
+
+    // stacks are numbered from one in the commands
+    let valid_stacks = 1..=initial_state.len();
+
+    // Both cranes move the same number of crates between the same stacks, so
+    // the heights can be followed here to check no move takes too many
+    let mut heights: Vec<usize> = initial_state.iter().map(LinkedList::len).collect();
+
+    for (command, (line_index, line)) in commands.iter().zip(command_lines.iter()) {
+        for (keyword, stack) in [("from", command.from_stack), ("to", command.to_stack)] {
+            if !valid_stacks.contains(&stack) {
+                return Err(ParseError::in_line(
+                    *line_index,
+                    line,
+                    &format!("{} {}", keyword, stack),
+                    format!("a stack number from 1 to {}", initial_state.len()),
+                ));
+            }
+        }
+
+        let from_height = heights[command.from_stack - 1];
+
+        if command.num_to_move > from_height {
+            return Err(ParseError::in_line(
+                *line_index,
+                line,
+                &format!("move {}", command.num_to_move),
+                format!(
+                    "at most the {} crates on stack {}",
+                    from_height, command.from_stack
+                ),
+            ));
+        }
+
+        heights[command.from_stack - 1] -= command.num_to_move;
+        heights[command.to_stack - 1] += command.num_to_move;
+    }
+
+    Ok((initial_state, commands))
+}
+
+fn execute_commands_pt1(stacks: &mut Stacks, commands: &Commands) {
+    for command in commands {
+        for _ in 0..command.num_to_move {
+            let element = stacks[command.from_stack - 1]
+                .pop_back()
+                .expect("parsing checks moves only take the crates there are");
+
+            stacks[command.to_stack - 1].push_back(element);
+        }
+    }
+}
+
+fn execute_commands_pt2(stacks: &mut Stacks, commands: &Commands) {
+    for command in commands {
+        let mut temp_stack = LinkedList::new();
+        for _ in 0..command.num_to_move {
+            let from_stack = &mut stacks[command.from_stack - 1];
+            let element = from_stack
+                .pop_back()
+                .expect("parsing checks moves only take the crates there are");
+
+            temp_stack.push_back(element);
+        }
+
+        for item in temp_stack.iter().rev() {
+            let to_stack = &mut stacks[command.to_stack - 1];
+            to_stack.push_back(*item);
+        }
+    }
+}
+
+// The crate on top of each stack, or None if a stack is empty
+fn get_top_of_each_stack(stacks: &Stacks) -> Option<String> {
+    stacks.iter().map(|stack| stack.back()).try_fold(
+        String::new(),
+        |mut acc: String, current_char| {
+            acc.push(*current_char?);
+
+            Some(acc)
+        },
+    )
+}
+
+fn tops_answer(stacks: &Stacks) -> Answer {
+    match get_top_of_each_stack(stacks) {
+        Some(tops) => tops.into(),
+        None => Answer::Failed(String::from("a stack ends up empty")),
+    }
+}
+
+// The number of characters that define a column in the input
+const COLUMN_SIZE: usize = 4;
+fn build_stacks(initial_state_lines: &[(usize, &str)]) -> Result<Stacks> {
+    // the last line of the drawing numbers each stack
+    let (numbers_line_index, numbers_line) = initial_state_lines
+        .last()
+        .ok_or_else(|| ParseError::new("", "a drawing of the starting stacks"))?;
+
+    if let Some(label) = numbers_line
+        .split_whitespace()
+        .find(|label| label.parse::<usize>().is_err())
+    {
+        return Err(ParseError::in_line(
+            *numbers_line_index,
+            numbers_line,
+            label,
+            "a line numbering each stack",
+        ));
+    }
+
+    let total_stacks = numbers_line.split_whitespace().count();
+
+    let mut stacks: Stacks = (0..total_stacks)
+        .map(|_| {
+            let empty_stack: LinkedList<char> = LinkedList::<char>::new();
+            empty_stack
+        })
+        .collect();
+
+    for (stack_index, stack) in stacks.iter_mut().enumerate() {
+        let char_index = stack_index * COLUMN_SIZE + 1;
+
+        // work back to front when pushing onto stacks
+        for (_, line) in initial_state_lines.iter().rev() {
+            // indicates a stack index line, do nothing
+            if !line.contains('[') {
+                continue;
+            }
+
+            // editors like to strip the trailing spaces of short stacks
+            let char_to_push = line.chars().nth(char_index);
+
+            if let Some(char_to_push) = char_to_push.filter(|item| !item.is_whitespace()) {
+                stack.push_back(char_to_push);
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Commands);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((stacks, commands): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+
+        execute_commands_pt1(&mut stacks, commands);
+
+        tops_answer(&stacks)
+    }
+
+    fn part2((stacks, commands): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+
+        execute_commands_pt2(&mut stacks, commands);
+
+        tops_answer(&stacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    common::example_tests!(crate::Day5);
+
+    #[test]
+    fn test_part1() {
+        let (mut stacks, commands) = parse_input(TEST_INPUT).unwrap();
+
+        execute_commands_pt1(&mut stacks, &commands);
+
+        assert_eq!(get_top_of_each_stack(&stacks).as_deref(), Some("CMZ"));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (mut stacks, commands) = parse_input(TEST_INPUT).unwrap();
+
+        execute_commands_pt2(&mut stacks, &commands);
+
+        assert_eq!(get_top_of_each_stack(&stacks).as_deref(), Some("MCD"));
+    }
+
+    #[test]
+    fn test_trailing_spaces_stripped() {
+        let stripped: String = TEST_INPUT
+            .lines()
+            .map(|line| format!("{}\n", line.trim_end()))
+            .collect();
+
+        let (mut stacks, commands) = parse_input(&stripped).unwrap();
+
+        execute_commands_pt1(&mut stacks, &commands);
+
+        assert_eq!(get_top_of_each_stack(&stacks).as_deref(), Some("CMZ"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("[A]\n 1 \n\nmove 1 frm 1 to 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: unexpected `move 1 frm 1 to 1`, expected a command like `move 1 from 2 to 1`"
+        );
+
+        let error = parse_input("[A]\n 1 \n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 15: unexpected `to 2`, expected a stack number from 1 to 1"
+        );
+
+        let error = parse_input("[A]\n 1 \n\nmove 2 from 1 to 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: unexpected `move 2`, expected at most the 1 crates on stack 1"
+        );
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let input = Day5::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 1").unwrap();
+
+        assert_eq!(
+            Day5::part1(&input),
+            Answer::Failed(String::from("a stack ends up empty"))
+        );
+    }
+}
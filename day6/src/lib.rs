@@ -0,0 +1,142 @@
+use common::{Answer, ParseError, Result, Solution};
+use itertools::Itertools;
+use log::debug;
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+// How many characters have been read once the last `buffer_size` are all
+// different, or None if that never happens. The input is ASCII, so each
+// character is a byte
+fn first_non_repeating_buffer(input: &str, buffer_size: usize) -> Option<usize> {
+    let start_index = input
+        .as_bytes()
+        .windows(buffer_size)
+        .position(|buffer| buffer.iter().unique().count() == buffer_size)?;
+
+    let end_index = start_index + buffer_size;
+
+    debug!("start of packet: {}", &input[start_index..end_index]);
+    Some(end_index)
+}
+
+fn marker_end(input: &str, buffer_size: usize) -> Answer {
+    match first_non_repeating_buffer(input, buffer_size) {
+        Some(end_index) => end_index.into(),
+        None => Answer::Failed(format!(
+            "no {} characters in a row are all different",
+            buffer_size
+        )),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    // The datastream is a single line of lowercase letters, long enough to
+    // hold a start-of-message marker
+    fn parse(input: &str) -> Result<Self::Input> {
+        let stream = input.trim_end_matches(['\n', '\r']);
+
+        if let Some((column_index, (byte_index, c))) = stream
+            .char_indices()
+            .enumerate()
+            .find(|(_, (_, c))| !c.is_ascii_lowercase())
+        {
+            return Err(ParseError::in_column(
+                0,
+                column_index,
+                &stream[byte_index..byte_index + c.len_utf8()],
+                "a lowercase letter",
+            ));
+        }
+
+        if stream.len() < MESSAGE_MARKER_SIZE {
+            return Err(ParseError::in_column(
+                0,
+                stream.len(),
+                "",
+                format!("at least {} characters", MESSAGE_MARKER_SIZE),
+            ));
+        }
+
+        Ok(String::from(stream))
+    }
+
+    // start of packet
+    fn part1(input: &Self::Input) -> Answer {
+        marker_end(input, PACKET_MARKER_SIZE)
+    }
+
+    // start of message
+    fn part2(input: &Self::Input) -> Answer {
+        marker_end(input, MESSAGE_MARKER_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    common::example_tests!(crate::Day6);
+
+    #[test]
+    fn test_part1() {
+        let test_inputs: Vec<(&str, usize)> = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+
+        for (input, expected) in test_inputs {
+            let actual = first_non_repeating_buffer(input, 4);
+
+            assert_eq!(actual, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_inputs: Vec<(&str, usize)> = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for (input, expected) in test_inputs {
+            let actual = first_non_repeating_buffer(input, 14);
+
+            assert_eq!(actual, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_no_marker() {
+        let input = Day6::parse("abcdabcdabcdabcd\n").unwrap();
+
+        assert_eq!(Day6::part1(&input), Answer::from(4usize));
+        assert_eq!(
+            Day6::part2(&input),
+            Answer::Failed(String::from("no 14 characters in a row are all different"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day6::parse("abc\n").unwrap_err().to_string(),
+            "line 1, column 4: unexpected end of line, expected at least 14 characters"
+        );
+        assert_eq!(
+            Day6::parse("abcdéfghijklmnop").unwrap_err().to_string(),
+            "line 1, column 5: unexpected `é`, expected a lowercase letter"
+        );
+        assert!(Day6::parse("abcdefg\nhijklmnop").is_err());
+    }
+}
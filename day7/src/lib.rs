@@ -0,0 +1,417 @@
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+
+use common::{Answer, ParseError, Result, Solution};
+use log::warn;
+use regex::Regex;
+
+#[derive(Debug)]
+pub enum Command {
+    ChangeDirectory { target: String },
+    ListCurrentDirectory { output: Vec<String> },
+}
+
+impl Command {
+    pub fn from(command: &str, option: Option<&str>) -> Result<Self> {
+        match (command, option) {
+            ("cd", Some(target)) if !target.is_empty() => Ok(Command::ChangeDirectory {
+                target: String::from(target),
+            }),
+            ("cd", _) => Err(ParseError::new("", "a directory to change into")),
+            ("ls", _) => Ok(Command::ListCurrentDirectory { output: Vec::new() }),
+            _ => Err(ParseError::new(command, "`cd` or `ls`")),
+        }
+    }
+
+    // Output lines are either `dir <name>` or `<size> <name>`
+    pub fn add_output(&mut self, line: &str) -> Result<()> {
+        match self {
+            Command::ListCurrentDirectory { output } => {
+                let line_split: Vec<&str> = line.trim().split(' ').collect();
+
+                match &line_split[..] {
+                    ["dir", _] => (),
+                    [size, _] => {
+                        size.parse::<u32>().map_err(|_| {
+                            ParseError::in_piece(line, size, "`dir` or a file size")
+                        })?;
+                    }
+                    _ => {
+                        return Err(ParseError::in_piece(
+                            line,
+                            line,
+                            "a listing like `dir <name>` or `<size> <name>`",
+                        ))
+                    }
+                }
+
+                output.push(String::from(line));
+
+                Ok(())
+            }
+            _ => Err(ParseError::in_piece(
+                line,
+                line,
+                "a command starting with `$`, since `cd` has no output",
+            )),
+        }
+    }
+}
+
+pub struct File {
+    name: String,
+    size: u32,
+    pub parent: Option<Rc<RefCell<File>>>,
+    children: Option<Vec<Rc<RefCell<File>>>>,
+}
+
+impl File {
+    pub fn new_directory(name: &str) -> Self {
+        File {
+            name: String::from(name),
+            size: 0,
+            parent: None,
+            children: Some(Vec::new()),
+        }
+    }
+
+    pub fn new_file(name: &str, size: u32) -> Self {
+        File {
+            name: String::from(name),
+            size,
+            parent: None,
+            children: None,
+        }
+    }
+
+    pub fn add_child(&mut self, parent: Rc<RefCell<File>>, mut child: File) -> &mut Self {
+        child.parent = Some(parent);
+
+        if let Some(children) = &mut self.children {
+            children.push(Rc::new(RefCell::new(child)))
+        } else {
+            warn!(
+                "Attempted to add a child to a file. File: {}, Child: {}",
+                self.name, child.name
+            );
+        }
+
+        self
+    }
+
+    // returns index of child with given name, or None if no child has that name
+    pub fn find_child_by_name(&self, name: &str) -> Option<Rc<RefCell<File>>> {
+        if let Some(children) = &self.children {
+            children
+                .iter()
+                .find(|child| child.borrow().name == name)
+                .cloned()
+        } else {
+            None
+        }
+    }
+
+    pub fn get_size(&self) -> u32 {
+        let size_of_children: u32 = if let Some(children) = &self.children {
+            children.iter().map(|child| child.borrow().get_size()).sum()
+        } else {
+            0
+        };
+
+        size_of_children + self.size
+    }
+
+    pub fn is_directory(&self) -> bool {
+        self.children.is_some()
+    }
+}
+
+// returns each command paired with the index of the line it was given on
+fn input_to_commands(input: &str) -> Result<Vec<(usize, Command)>> {
+    let mut commands: Vec<(usize, Command)> = Vec::new();
+
+    let command_pattern = Regex::new(r"\$ (?P<command>\w+)\s*(?P<option>.*)").unwrap();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let pattern_matches = command_pattern.captures(line.trim());
+        if let Some(captures) = pattern_matches {
+            let command = captures.name("command").unwrap().as_str(); // required
+            let option = captures.name("option").map(|val| val.as_str()); // optional
+
+            let command = Command::from(command, option).map_err(|err| err.at(line_index, line))?;
+
+            commands.push((line_index, command));
+        } else if let Some((_, current_command)) = commands.last_mut() {
+            current_command
+                .add_output(line)
+                .map_err(|err| err.at(line_index, line))?;
+        } else if !line.is_empty() {
+            return Err(ParseError::in_line(
+                line_index,
+                line,
+                line,
+                "a command starting with `$`",
+            ));
+        }
+    }
+
+    Ok(commands)
+}
+
+// `input` is only used to point at the offending line when a `cd` fails
+fn execute_commands(input: &str, commands: &[(usize, Command)]) -> Result<Rc<RefCell<File>>> {
+    let root = Rc::new(RefCell::new(File::new_directory("/")));
+    let mut current_file = root.clone();
+
+    // the target is the rest of the `cd` line, so it's found at the end
+    // rather than searched for, which could find the `cd` itself
+    let locate = |line_index: usize, target: &str, expected: &str| {
+        let line = input.lines().nth(line_index).unwrap_or("");
+        let end = line.trim_end().len();
+
+        match end.checked_sub(target.len()) {
+            Some(start) => ParseError::in_span(line_index, line, start..end, expected),
+            None => ParseError::in_line(line_index, line, target, expected),
+        }
+    };
+
+    for (line_index, command) in commands {
+        match command {
+            Command::ChangeDirectory { target } if target == "/" => current_file = root.clone(),
+            Command::ChangeDirectory { target } if target == ".." => {
+                let parent = current_file.borrow().parent.clone().ok_or_else(|| {
+                    locate(
+                        *line_index,
+                        target,
+                        "a directory to change into, since `/` has no parent",
+                    )
+                })?;
+
+                current_file = parent;
+            }
+            Command::ChangeDirectory { target } => {
+                let new_directory = current_file
+                    .borrow()
+                    .find_child_by_name(target)
+                    .filter(|child| child.borrow().is_directory())
+                    .ok_or_else(|| {
+                        locate(*line_index, target, "a directory listed by an earlier `ls`")
+                    })?;
+
+                current_file = new_directory;
+            }
+            Command::ListCurrentDirectory { output } => {
+                // output was validated by `Command::add_output`
+                for line in output {
+                    let line_split: Vec<&str> = line.trim().split(' ').collect();
+
+                    if let [first, second] = &line_split[..] {
+                        if *first == "dir" {
+                            current_file
+                                .deref()
+                                .borrow_mut()
+                                .add_child(current_file.clone(), File::new_directory(second));
+                        } else {
+                            let size: u32 = first.parse().unwrap();
+                            current_file
+                                .deref()
+                                .borrow_mut()
+                                .add_child(current_file.clone(), File::new_file(second, size));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(root)
+}
+
+// for each dir sized < 100_000, sum them
+fn find_size_p1(current_node: Rc<RefCell<File>>) -> u32 {
+    // only interested in directories:
+    if !current_node.borrow().is_directory() {
+        return 0;
+    }
+
+    let my_size = current_node.borrow().get_size();
+
+    let nested_dir_sizes: u32 = current_node
+        .borrow()
+        .children
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|child| find_size_p1(child.clone()))
+        .sum();
+
+    if my_size < 100_000 {
+        my_size + nested_dir_sizes
+    } else {
+        nested_dir_sizes
+    }
+}
+
+// returns a vec of (file_size, file_name)
+// only returns directories that are large enough to free enough space
+fn find_candidates_to_delete(root: Rc<RefCell<File>>, need_to_delete: u32) -> Vec<(u32, String)> {
+    if !root.borrow().is_directory() {
+        return Vec::new();
+    }
+
+    let my_size = root.borrow().get_size();
+
+    let mut child_candidates: Vec<(u32, String)> = root
+        .deref()
+        .borrow()
+        .children
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter(|child| child.borrow().is_directory())
+        .map(|child_dir| find_candidates_to_delete(child_dir.clone(), need_to_delete))
+        .collect::<Vec<Vec<(u32, String)>>>()
+        .concat();
+
+    let mut to_concat = if my_size >= need_to_delete {
+        vec![(my_size, root.borrow().name.to_string())]
+    } else {
+        Vec::new()
+    };
+
+    child_candidates.append(&mut to_concat);
+
+    child_candidates
+}
+
+// The smallest directory that frees up enough space, or why there isn't one
+fn find_directory_to_delete(root: Rc<RefCell<File>>) -> std::result::Result<(u32, String), String> {
+    const TOTAL_DISK: u32 = 70_000_000;
+    const DISK_NEEDED: u32 = 30_000_000;
+
+    let current_used: u32 = root.borrow().get_size();
+
+    let available_disk = TOTAL_DISK.checked_sub(current_used).ok_or_else(|| {
+        format!(
+            "the files take up {}, more than the whole disk of {}",
+            current_used, TOTAL_DISK
+        )
+    })?;
+
+    let need_to_delete = match DISK_NEEDED.checked_sub(available_disk) {
+        Some(need_to_delete) if need_to_delete > 0 => need_to_delete,
+        _ => {
+            return Err(format!(
+                "{} is already free, so nothing needs deleting",
+                available_disk
+            ))
+        }
+    };
+
+    let mut candidates = find_candidates_to_delete(root, need_to_delete);
+
+    candidates.sort_by_key(|(size1, _)| *size1);
+
+    // the root is always a candidate, since it holds everything in use
+    Ok((candidates[0].0, candidates[0].1.clone()))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Rc<RefCell<File>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands = input_to_commands(input)?;
+
+        execute_commands(input, &commands)
+    }
+
+    fn part1(root: &Self::Input) -> Answer {
+        find_size_p1(root.clone()).into()
+    }
+
+    fn part2(root: &Self::Input) -> Answer {
+        match find_directory_to_delete(root.clone()) {
+            Ok((size, _directory)) => size.into(),
+            Err(reason) => Answer::Failed(reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    use crate::*;
+
+    common::example_tests!(crate::Day7);
+
+    #[test]
+    fn test_part1() {
+        let commands = input_to_commands(TEST_INPUT).unwrap();
+
+        let root = execute_commands(TEST_INPUT, &commands).unwrap();
+
+        let size = find_size_p1(root.clone());
+
+        println!("sum of < 100_000: {}", size);
+
+        assert_eq!(size, 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        let commands = input_to_commands(TEST_INPUT).unwrap();
+
+        let root = execute_commands(TEST_INPUT, &commands).unwrap();
+
+        let (size, directory) = find_directory_to_delete(root).unwrap();
+
+        assert_eq!(directory, "d");
+        assert_eq!(size, 24933642);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day7::parse("$ cd /\n$ ls\n12x b.txt").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected `12x`, expected `dir` or a file size"
+        );
+
+        let error = Day7::parse("$ cd /\n$ rm -rf a").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected `rm`, expected `cd` or `ls`"
+        );
+
+        let error = Day7::parse("$ cd /\n$ ls\ndir a\n$ cd b").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 6: unexpected `b`, expected a directory listed by an earlier `ls`"
+        );
+
+        // a directory with the same name as the command
+        let error = Day7::parse("$ cd /\n$ cd cd").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: unexpected `cd`, expected a directory listed by an earlier `ls`"
+        );
+    }
+
+    #[test]
+    fn test_enough_space_already() {
+        let root = Day7::parse("$ cd /\n$ ls\n100 a.txt").unwrap();
+
+        assert_eq!(
+            Day7::part2(&root),
+            Answer::Failed(String::from(
+                "69999900 is already free, so nothing needs deleting"
+            ))
+        );
+
+        let root = Day7::parse("$ cd /\n$ ls\n80000000 a.txt").unwrap();
+
+        assert!(matches!(Day7::part2(&root), Answer::Failed(_)));
+    }
+}
@@ -0,0 +1,200 @@
+use common::Grid;
+
+use crate::Forest;
+
+// how many pixels wide and tall each tree is in the images
+const TREE_PIXELS: usize = 4;
+
+// From dark purple for the lowest values up to yellow for the highest
+const COLOR_SCALE: [[u8; 3]; 5] = [
+    [13, 8, 135],
+    [126, 3, 168],
+    [204, 71, 120],
+    [248, 149, 64],
+    [240, 249, 33],
+];
+
+// The colour for a value between 0 and 1 along the colour scale
+fn scale_color(fraction: f64) -> [u8; 3] {
+    let position = fraction.clamp(0.0, 1.0) * (COLOR_SCALE.len() - 1) as f64;
+
+    let low = position.floor() as usize;
+    let high = (low + 1).min(COLOR_SCALE.len() - 1);
+    let blend = position - low as f64;
+
+    let mut color = [0; 3];
+    for channel in 0..3 {
+        let (from, to) = (COLOR_SCALE[low][channel], COLOR_SCALE[high][channel]);
+
+        color[channel] = (from as f64 + (to as f64 - from as f64) * blend).round() as u8;
+    }
+
+    color
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Heatmap {
+    // visible trees are the top of the scale and hidden trees the bottom
+    Visibility,
+    // Scores are spread over the scale logarithmically, since a few trees
+    // score far higher than the rest
+    ScenicScore,
+}
+
+impl Forest {
+    fn heatmap_colors(&self, heatmap: Heatmap) -> Grid<[u8; 3]> {
+        match heatmap {
+            Heatmap::Visibility => self
+                .visibility_map()
+                .map(|is_visible| scale_color(if *is_visible { 1.0 } else { 0.0 })),
+            Heatmap::ScenicScore => {
+                let scenic_scores = self.scenic_score_map();
+
+                let max_score = scenic_scores.iter().map(|(_, score)| *score).max();
+                let log_max = (max_score.unwrap_or(0) as f64).ln_1p();
+
+                scenic_scores.map(|score| {
+                    let fraction = if log_max > 0.0 {
+                        (*score as f64).ln_1p() / log_max
+                    } else {
+                        0.0
+                    };
+
+                    scale_color(fraction)
+                })
+            }
+        }
+    }
+
+    // the image's width and height in pixels, and its pixels row by row as RGB
+    fn heatmap_pixels(&self, heatmap: Heatmap) -> (usize, usize, Vec<u8>) {
+        let colors = self.heatmap_colors(heatmap);
+
+        let width = colors.width() * TREE_PIXELS;
+        let height = colors.height() * TREE_PIXELS;
+
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for y in 0..height {
+            for x in 0..width {
+                pixels.extend(colors[(x / TREE_PIXELS, y / TREE_PIXELS)]);
+            }
+        }
+
+        (width, height, pixels)
+    }
+
+    // The heatmap as a binary PPM image
+    pub fn heatmap_ppm(&self, heatmap: Heatmap) -> Vec<u8> {
+        let (width, height, pixels) = self.heatmap_pixels(heatmap);
+
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        ppm.extend(pixels);
+
+        ppm
+    }
+
+    // The heatmap as a PNG image
+    pub fn heatmap_png(&self, heatmap: Heatmap) -> Vec<u8> {
+        let (width, height, pixels) = self.heatmap_pixels(heatmap);
+
+        let mut png = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        // Writing to memory can only fail if the image is empty, which parsing
+        // rules out, or if the pixels don't fill it
+        let mut writer = encoder
+            .write_header()
+            .expect("forests have at least one tree");
+        writer
+            .write_image_data(&pixels)
+            .expect("there's a pixel for every tree");
+        writer.finish().expect("the image is complete");
+
+        png
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::heatmap::*;
+
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    static PPM_HEADER: &[u8] = b"P6\n20 20\n255\n";
+
+    // the colour of a tree in a PPM heatmap of the example
+    fn tree_color(ppm: &[u8], row: usize, column: usize) -> [u8; 3] {
+        let pixel = (row * TREE_PIXELS * 20 + column * TREE_PIXELS) * 3;
+        let start = PPM_HEADER.len() + pixel;
+
+        [ppm[start], ppm[start + 1], ppm[start + 2]]
+    }
+
+    #[test]
+    fn test_scale_color() {
+        assert_eq!(scale_color(0.0), COLOR_SCALE[0]);
+        assert_eq!(scale_color(0.5), COLOR_SCALE[2]);
+        assert_eq!(scale_color(1.0), COLOR_SCALE[4]);
+        assert_eq!(scale_color(2.0), COLOR_SCALE[4]);
+        assert_eq!(scale_color(0.125), [70, 6, 152]);
+    }
+
+    #[test]
+    fn test_visibility_ppm() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        let ppm = forest.heatmap_ppm(Heatmap::Visibility);
+
+        assert!(ppm.starts_with(PPM_HEADER));
+        assert_eq!(ppm.len(), PPM_HEADER.len() + 20 * 20 * 3);
+
+        assert_eq!(tree_color(&ppm, 0, 0), scale_color(1.0));
+        assert_eq!(tree_color(&ppm, 1, 3), scale_color(0.0));
+    }
+
+    #[test]
+    fn test_scenic_score_ppm() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        let ppm = forest.heatmap_ppm(Heatmap::ScenicScore);
+
+        // the best spot, and an edge tree which scores 0
+        assert_eq!(tree_color(&ppm, 3, 2), scale_color(1.0));
+        assert_eq!(tree_color(&ppm, 0, 0), scale_color(0.0));
+    }
+
+    #[test]
+    fn test_png_matches_ppm() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        let png = forest.heatmap_png(Heatmap::ScenicScore);
+
+        let mut reader = png::Decoder::new(Cursor::new(png)).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (20, 20));
+        assert_eq!(
+            pixels,
+            &forest.heatmap_ppm(Heatmap::ScenicScore)[PPM_HEADER.len()..]
+        );
+    }
+
+    #[test]
+    fn test_single_tree_png() {
+        let forest = Forest::from_input("5").unwrap();
+
+        let png = forest.heatmap_png(Heatmap::Visibility);
+
+        let reader = png::Decoder::new(Cursor::new(png)).read_info().unwrap();
+
+        assert_eq!(reader.info().width as usize, TREE_PIXELS);
+        assert_eq!(reader.info().height as usize, TREE_PIXELS);
+    }
+}
@@ -0,0 +1,491 @@
+mod heatmap;
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use common::{Answer, Direction, Grid, Position, Result, Solution};
+
+pub use heatmap::Heatmap;
+
+const MAX_HEIGHT: u8 = 9;
+
+pub struct Forest {
+    tree_heights: Grid<u8>,
+}
+
+impl Forest {
+    pub fn from_input(input: &str) -> Result<Self> {
+        // every row needs to be as wide as the first for the maps to line up
+        let tree_heights = Grid::parse(input, "trees", |digit| {
+            digit
+                .to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| String::from("a tree height from 0 to 9"))
+        })?;
+
+        Ok(Forest { tree_heights })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.tree_heights.height()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.tree_heights.width()
+    }
+
+    // the height of the tree in the row and column, if it's in the forest
+    pub fn height_at(&self, row: usize, column: usize) -> Option<u8> {
+        self.tree_heights.get((column, row)).copied()
+    }
+
+    // Every line of trees that looks out over the edge in `direction`, each
+    // starting at the edge and heading back across the forest
+    fn sight_lines(&self, direction: Direction) -> impl Iterator<Item = Vec<Position>> + '_ {
+        self.tree_heights
+            .positions()
+            .filter(move |position| self.tree_heights.step(*position, direction).is_none())
+            .map(move |edge| {
+                std::iter::once(edge)
+                    .chain(self.tree_heights.ray(edge, direction.opposite()))
+                    .collect()
+            })
+    }
+
+    // A tree is visible from an edge when it's taller than every tree before
+    // it along a sight line from that edge, so each line only needs one pass
+    pub fn visibility_map(&self) -> Grid<bool> {
+        let mut visibility =
+            Grid::new(self.tree_heights.width(), self.tree_heights.height(), false);
+
+        for direction in Direction::CARDINAL {
+            for line in self.sight_lines(direction) {
+                let mut tallest = None;
+
+                for position in line {
+                    let tree_height = self.tree_heights[position];
+
+                    if tallest.is_none_or(|tallest| tree_height > tallest) {
+                        visibility[position] = true;
+                        tallest = Some(tree_height);
+                    }
+                }
+            }
+        }
+
+        visibility
+    }
+
+    pub fn scenic_score_map(&self) -> Grid<usize> {
+        self.viewshed_scores(&Direction::CARDINAL, 0)
+    }
+
+    // A generalised scenic score: the product of the viewing distances in
+    // each of `directions`, for an observer `observer_height` above the top of
+    // each tree. Only trees at least as high as the observer block the view.
+    //
+    // Works along each sight line remembering where the last tree of each
+    // height was, so the nearest blocker is one of those ten trees
+    pub fn viewshed_scores(&self, directions: &[Direction], observer_height: u8) -> Grid<usize> {
+        let mut scores = Grid::new(self.tree_heights.width(), self.tree_heights.height(), 1);
+
+        for direction in directions {
+            for line in self.sight_lines(*direction) {
+                let mut last_of_height: [Option<usize>; MAX_HEIGHT as usize + 1] =
+                    [None; MAX_HEIGHT as usize + 1];
+
+                for (index, position) in line.into_iter().enumerate() {
+                    let tree_height = self.tree_heights[position];
+
+                    let eye_level =
+                        (tree_height as usize + observer_height as usize).min(last_of_height.len());
+
+                    let blocker = last_of_height[eye_level..].iter().flatten().max();
+
+                    // with nothing in the way the view reaches the edge
+                    let viewing_distance = match blocker {
+                        Some(blocker_index) => index - blocker_index,
+                        None => index,
+                    };
+
+                    scores[position] *= viewing_distance;
+                    last_of_height[tree_height as usize] = Some(index);
+                }
+            }
+        }
+
+        scores
+    }
+
+    // the number of trees that can be seen from outside the forest
+    pub fn visible_count(&self) -> usize {
+        self.visibility_map()
+            .iter()
+            .filter(|(_, is_visible)| **is_visible)
+            .count()
+    }
+
+    // The tree with the highest scenic score, as (row, column, score). Ties go
+    // to the first tree row by row. None if there are no trees
+    pub fn best_scenic_spot(&self) -> Option<(usize, usize, usize)> {
+        self.scenic_score_map()
+            .iter()
+            .min_by_key(|(_, score)| Reverse(**score))
+            .map(|((column, row), score)| (row, column, *score))
+    }
+
+    // The trees that can be seen from the tree at the row and column, as
+    // (row, column), looking up, right, down and left. Each direction's trees
+    // are nearest first, and end with the tree that blocks the view if there
+    // is one. Panics if the position is outside the forest
+    pub fn visible_from(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> HashMap<Direction, Vec<(usize, usize)>> {
+        let tree_height = self.tree_heights[(column, row)];
+
+        Direction::CARDINAL
+            .into_iter()
+            .map(|direction| {
+                let mut visible = Vec::new();
+
+                for (other_column, other_row) in self.tree_heights.ray((column, row), direction) {
+                    visible.push((other_row, other_column));
+
+                    if self.tree_heights[(other_column, other_row)] >= tree_height {
+                        break;
+                    }
+                }
+
+                (direction, visible)
+            })
+            .collect()
+    }
+
+    // Whether the tree at `from` can see the tree at `to`, both as (row,
+    // column). They have to share a row, column or diagonal, and every tree
+    // between them has to be shorter than the one at `from`. A tree can't see
+    // itself. Panics if `from` is outside the forest
+    pub fn line_of_sight(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let (from_row, from_column) = from;
+        let (to_row, to_column) = to;
+
+        let columns_apart = to_column as isize - from_column as isize;
+        let rows_apart = to_row as isize - from_row as isize;
+
+        let in_line =
+            columns_apart == 0 || rows_apart == 0 || columns_apart.abs() == rows_apart.abs();
+
+        let step = (columns_apart.signum(), rows_apart.signum());
+
+        let direction = match Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == step)
+        {
+            Some(direction) if in_line => direction,
+            _ => return false,
+        };
+
+        let tree_height = self.tree_heights[(from_column, from_row)];
+
+        for position in self.tree_heights.ray((from_column, from_row), direction) {
+            if position == (to_column, to_row) {
+                return true;
+            }
+
+            if self.tree_heights[position] >= tree_height {
+                return false;
+            }
+        }
+
+        false // `to` is outside the forest
+    }
+
+    // The straightforward version of `visibility_map`, which looks all the way
+    // to the edge from every tree. Kept to check the faster version against
+    pub fn create_visibility_map(&self) -> Grid<bool> {
+        let mut visibility =
+            Grid::new(self.tree_heights.width(), self.tree_heights.height(), false);
+
+        for position in self.tree_heights.positions() {
+            let tree_height = self.tree_heights[position];
+
+            // trees on the edge have nothing in the way, so they're always visible
+            visibility[position] = Direction::CARDINAL.into_iter().any(|direction| {
+                self.tree_heights
+                    .ray(position, direction)
+                    .all(|other| self.tree_heights[other] < tree_height)
+            });
+        }
+
+        visibility
+    }
+
+    // how many trees can be seen from the position before one blocks the view
+    fn viewing_distance(&self, position: Position, direction: Direction) -> usize {
+        let tree_height = self.tree_heights[position];
+
+        let mut distance = 0;
+
+        for other in self.tree_heights.ray(position, direction) {
+            distance += 1; // the tree blocking our sight can still be seen
+
+            if self.tree_heights[other] >= tree_height {
+                break;
+            }
+        }
+
+        distance
+    }
+
+    // The straightforward version of `scenic_score_map`
+    pub fn create_scenic_score_map(&self) -> Grid<usize> {
+        let mut scenic_scores = Grid::new(self.tree_heights.width(), self.tree_heights.height(), 0);
+
+        for position in self.tree_heights.positions() {
+            scenic_scores[position] = Direction::CARDINAL
+                .into_iter()
+                .map(|direction| self.viewing_distance(position, direction))
+                .product();
+        }
+
+        scenic_scores
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Forest::from_input(input)
+    }
+
+    fn part1(forest: &Self::Input) -> Answer {
+        forest.visible_count().into()
+    }
+
+    fn part2(forest: &Self::Input) -> Answer {
+        let max_score = forest.best_scenic_spot().map_or(0, |(_, _, score)| score);
+
+        max_score.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    use proptest::prelude::*;
+
+    use crate::*;
+
+    // forests of up to 12x12 trees, as puzzle input
+    fn forests() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize)
+            .prop_flat_map(|(width, height)| {
+                prop::collection::vec(prop::collection::vec(0..10u32, width), height)
+            })
+            .prop_map(|rows| {
+                rows.iter()
+                    .map(|row| row.iter().map(|height| height.to_string()).collect())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+    }
+
+    common::example_tests!(crate::Day8);
+
+    #[test]
+    fn part1() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        let visibility = forest.create_visibility_map();
+
+        let visible_count = visibility
+            .iter()
+            .filter(|(_, is_visible)| **is_visible)
+            .count();
+
+        assert_eq!(visible_count, 21);
+    }
+
+    #[test]
+    fn part2() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        let scenic_scores = forest.create_scenic_score_map();
+
+        println!("scenic_scores:\n{:?}", scenic_scores);
+
+        let max_score = scenic_scores.iter().map(|(_, score)| *score).max();
+
+        assert_eq!(max_score, Some(8));
+    }
+
+    #[test]
+    fn test_sweeps() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        assert_eq!(forest.visibility_map(), forest.create_visibility_map());
+        assert_eq!(forest.scenic_score_map(), forest.create_scenic_score_map());
+        assert_eq!(forest.scenic_score_map()[(2, 3)], 8);
+    }
+
+    fn directions() -> impl Strategy<Value = Vec<Direction>> {
+        prop::sample::subsequence(Direction::ALL.to_vec(), 0..=8)
+    }
+
+    proptest! {
+        #[test]
+        fn test_viewshed_scores_match_reference(
+            input in forests(),
+            directions in directions(),
+            observer_height in 0..12u8,
+        ) {
+            let forest = Forest::from_input(&input).unwrap();
+
+            prop_assert_eq!(
+                forest.viewshed_scores(&directions, observer_height),
+                reference_viewshed(&forest, &directions, observer_height)
+            );
+        }
+
+        #[test]
+        fn test_visibility_map_matches_reference(input in forests()) {
+            let forest = Forest::from_input(&input).unwrap();
+
+            prop_assert_eq!(forest.visibility_map(), forest.create_visibility_map());
+        }
+
+        #[test]
+        fn test_scenic_score_map_matches_reference(input in forests()) {
+            let forest = Forest::from_input(&input).unwrap();
+
+            prop_assert_eq!(forest.scenic_score_map(), forest.create_scenic_score_map());
+        }
+    }
+
+    // looks out from every tree to count its viewing distances, like `create_scenic_score_map`
+    fn reference_viewshed(
+        forest: &Forest,
+        directions: &[Direction],
+        observer_height: u8,
+    ) -> Grid<usize> {
+        let heights = &forest.tree_heights;
+        let mut scores = Grid::new(heights.width(), heights.height(), 1);
+
+        for position in heights.positions() {
+            let eye_level = heights[position] + observer_height;
+
+            for direction in directions {
+                let mut distance = 0;
+
+                for other in heights.ray(position, *direction) {
+                    distance += 1;
+
+                    if heights[other] >= eye_level {
+                        break;
+                    }
+                }
+
+                scores[position] *= distance;
+            }
+        }
+
+        scores
+    }
+
+    #[test]
+    fn test_viewshed_scores() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        // the diagonals add 2 up and to the right, and 1 in the others
+        assert_eq!(forest.viewshed_scores(&Direction::ALL, 0)[(2, 3)], 16);
+
+        // high enough to see over every tree: 3 up, 2 right, 1 down and 2 left
+        let scores = forest.viewshed_scores(&Direction::CARDINAL, MAX_HEIGHT + 1);
+        assert_eq!(scores[(2, 3)], 12);
+
+        // one higher sees over the 5 above, all the way to the edge
+        let scores = forest.viewshed_scores(&[Direction::Up], 1);
+        assert_eq!(scores[(2, 3)], 3);
+        assert_eq!(scores, reference_viewshed(&forest, &[Direction::Up], 1));
+    }
+
+    #[test]
+    fn test_queries() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        assert_eq!(forest.visible_count(), 21);
+        assert_eq!(forest.best_scenic_spot(), Some((3, 2, 8)));
+
+        let visible = forest.visible_from(3, 2);
+        assert_eq!(visible[&Direction::Up], vec![(2, 2), (1, 2)]);
+        assert_eq!(visible[&Direction::Right], vec![(3, 3), (3, 4)]);
+        assert_eq!(visible[&Direction::Down], vec![(4, 2)]);
+        assert_eq!(visible[&Direction::Left], vec![(3, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        assert!(forest.line_of_sight((3, 2), (1, 2)));
+        assert!(forest.line_of_sight((3, 2), (4, 3)));
+        assert!(forest.line_of_sight((0, 3), (4, 3)));
+
+        // the 5 at (1, 2) is as tall as the tree looking past it
+        assert!(!forest.line_of_sight((3, 2), (0, 2)));
+        assert!(!forest.line_of_sight((3, 2), (1, 0)));
+
+        // not in a straight line
+        assert!(!forest.line_of_sight((0, 0), (1, 2)));
+        assert!(!forest.line_of_sight((2, 2), (2, 2)));
+        assert!(!forest.line_of_sight((0, 0), (0, 7)));
+    }
+
+    #[test]
+    fn test_heights() {
+        let forest = Forest::from_input(TEST_INPUT).unwrap();
+
+        assert_eq!(forest.rows(), 5);
+        assert_eq!(forest.columns(), 5);
+        assert_eq!(forest.height_at(0, 3), Some(7));
+        assert_eq!(forest.height_at(3, 2), Some(5));
+        assert_eq!(forest.height_at(4, 4), Some(0));
+        assert_eq!(forest.height_at(5, 0), None);
+    }
+
+    #[test]
+    fn test_non_digits() {
+        let error = Forest::from_input("303\n2a5").err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected `a`, expected a tree height from 0 to 9"
+        );
+    }
+
+    #[test]
+    fn test_empty_forest() {
+        let error = Forest::from_input("").err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "invalid input, expected at least one row of trees"
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let error = Forest::from_input("303\n25\n653").err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `25`, expected a row of 3 trees, like the first row"
+        );
+    }
+}
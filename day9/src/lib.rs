@@ -1,4 +1,7 @@
-use std::{borrow::BorrowMut, collections::HashSet};
+use std::collections::HashSet;
+
+use common::{Answer, ParseError, Result, Solution};
+use log::trace;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Position {
@@ -35,11 +38,13 @@ pub struct MovementCommand {
     pub num_steps: usize,
 }
 
-fn input_to_commands(input: &String) -> Vec<MovementCommand> {
+fn input_to_commands(input: &str) -> Result<Vec<MovementCommand>> {
     input
         .lines()
-        .map(|line| {
-            let split_line: Vec<&str> = line.split(" ").collect();
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| {
+            let split_line: Vec<&str> = line.split(' ').collect();
 
             if let [direction, num_steps] = &split_line[..] {
                 let direction = match *direction {
@@ -47,24 +52,38 @@ fn input_to_commands(input: &String) -> Vec<MovementCommand> {
                     "R" => Direction::RIGHT,
                     "U" => Direction::UP,
                     "D" => Direction::DOWN,
-                    _ => panic!("invalid direction"),
+                    _ => {
+                        return Err(ParseError::in_line(
+                            line_index,
+                            line,
+                            direction,
+                            "one of `L`, `R`, `U` or `D`",
+                        ))
+                    }
                 };
 
-                let num_steps = num_steps.parse().unwrap();
+                let num_steps = num_steps.parse().map_err(|_| {
+                    ParseError::in_line(line_index, line, num_steps, "a number of steps")
+                })?;
 
-                MovementCommand {
+                Ok(MovementCommand {
                     direction,
                     num_steps,
-                }
+                })
             } else {
-                panic!("invalid number of chars in string");
+                Err(ParseError::in_line(
+                    line_index,
+                    line,
+                    line,
+                    "a direction and a number of steps, like `R 4`",
+                ))
             }
         })
         .collect()
 }
 
 // returns number of unique locations the tail visits
-fn part1(commands: &Vec<MovementCommand>) -> usize {
+fn part1(commands: &[MovementCommand]) -> usize {
     let mut head = Position { x: 0, y: 0 };
     let mut tail = Position { x: 0, y: 0 };
 
@@ -92,7 +111,7 @@ fn part1(commands: &Vec<MovementCommand>) -> usize {
 }
 
 // returns number of unique locations the tail visits
-fn part2(commands: &Vec<MovementCommand>) -> usize {
+fn part2(commands: &[MovementCommand]) -> usize {
     let mut nodes = vec![Position { x: 0, y: 0 }; 10];
 
     let mut tail_visited = HashSet::new();
@@ -113,7 +132,7 @@ fn part2(commands: &Vec<MovementCommand>) -> usize {
                 let previous_node = new_positions[index - 1];
 
                 if !node.is_adjacent(&previous_node) {
-                    let mut node_clone = node.clone();
+                    let mut node_clone = *node;
                     node_clone.step_towards(&previous_node);
 
                     new_positions[index] = node_clone;
@@ -122,7 +141,7 @@ fn part2(commands: &Vec<MovementCommand>) -> usize {
 
             nodes = new_positions;
 
-            render(&nodes);
+            trace!("\n{}", render(&nodes));
 
             tail_visited.insert((nodes[9].x, nodes[9].y));
         }
@@ -131,7 +150,7 @@ fn part2(commands: &Vec<MovementCommand>) -> usize {
     tail_visited.len()
 }
 
-fn render(rope_stuff: &Vec<Position>) {
+fn render(rope_stuff: &[Position]) -> String {
     let min_y = rope_stuff.iter().map(|pos| pos.y).min().unwrap();
     let max_y = rope_stuff.iter().map(|pos| pos.y).max().unwrap();
     let min_x = rope_stuff.iter().map(|pos| pos.x).min().unwrap();
@@ -155,55 +174,45 @@ fn render(rope_stuff: &Vec<Position>) {
         output[output_row][output_col] = display;
     }
 
-    println!();
-
-    for line in output {
-        println!("{}", line.join(""));
-    }
-
-    println!();
+    output
+        .iter()
+        .map(|line| line.join(""))
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    let commands = input_to_commands(&input);
+pub struct Day9;
 
-    let num_visited = part1(&commands);
+impl Solution for Day9 {
+    type Input = Vec<MovementCommand>;
 
-    println!("visited pt1: {}", num_visited);
+    fn parse(input: &str) -> Result<Self::Input> {
+        input_to_commands(input)
+    }
 
-    let num_visited = part2(&commands);
+    fn part1(commands: &Self::Input) -> Answer {
+        part1(commands).into()
+    }
 
-    println!("visited pt2: {}", num_visited);
+    fn part2(commands: &Self::Input) -> Answer {
+        part2(commands).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use core::num;
 
     use crate::input_to_commands;
 
-    static TEST_INPUT: &str = "R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2";
-
-    static TEST2_INPUT: &str = "R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20";
+    static TEST_INPUT: &str = include_str!("../examples/1.txt");
+
+    static TEST2_INPUT: &str = include_str!("../examples/2.txt");
+
+    common::example_tests!(crate::Day9);
 
     #[test]
     fn part1() {
-        let commands = input_to_commands(&String::from(TEST_INPUT));
+        let commands = input_to_commands(TEST_INPUT).unwrap();
 
         let num_visited = crate::part1(&commands);
 
@@ -212,10 +221,25 @@ U 20";
 
     #[test]
     fn part2() {
-        let commands = input_to_commands(&String::from(TEST2_INPUT));
+        let commands = input_to_commands(TEST2_INPUT).unwrap();
 
         let num_visited = crate::part2(&commands);
 
         assert_eq!(num_visited, 36);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = input_to_commands("R 4\nX 3").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected `X`, expected one of `L`, `R`, `U` or `D`"
+        );
+
+        let error = input_to_commands("R four").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: unexpected `four`, expected a number of steps"
+        );
+    }
 }